@@ -0,0 +1,66 @@
+// Solves the matrix game of Lab 6 without the window:
+//   ma-labs-solve [--json] [--decimals] [FILE]
+// The matrix is read from FILE or from stdin if FILE is missing or "-"
+use std::{
+    env, fs,
+    io::{self, ErrorKind, Read, Write},
+    process,
+};
+
+const USAGE: &str = "Usage: ma-labs-solve [--json] [--decimals] [FILE]
+
+Reads a payoff matrix as CSV, JSON or rows of values separated by spaces
+from FILE or stdin, prints max-min, min-max, saddle points and the mixed solution.
+
+Options:
+  --json      Print results as JSON
+  --decimals  Print exact solutions as decimals instead of fractions
+  -h, --help  Print this help";
+
+fn main() {
+    let mut json = false;
+    let mut fractions = true;
+    let mut path = None;
+    for arg in env::args().skip(1) {
+        match arg.as_str() {
+            "--json" => json = true,
+            "--decimals" => fractions = false,
+            "-h" | "--help" => {
+                print(USAGE);
+                return;
+            }
+            "-" => path = None,
+            arg if arg.starts_with('-') => fail(&format!("Unknown option \"{arg}\"\n\n{USAGE}")),
+            arg => path = Some(arg.to_string()),
+        }
+    }
+
+    let text = match &path {
+        Some(path) => fs::read_to_string(path).map_err(|err| format!("{path}: {err}")),
+        None => {
+            let mut text = String::new();
+            io::stdin()
+                .read_to_string(&mut text)
+                .map(|_| text)
+                .map_err(|err| format!("stdin: {err}"))
+        }
+    };
+
+    match text.and_then(|text| ma_labs::solve(&text, json, fractions)) {
+        Ok(output) => print(&output),
+        Err(error) => fail(&error),
+    }
+}
+
+// Output closed early, e.g. by `head`, isn't an error
+fn print(text: &str) {
+    match writeln!(io::stdout().lock(), "{text}") {
+        Err(err) if err.kind() != ErrorKind::BrokenPipe => fail(&format!("stdout: {err}")),
+        _ => {}
+    }
+}
+
+fn fail(error: &str) -> ! {
+    eprintln!("{error}");
+    process::exit(1);
+}
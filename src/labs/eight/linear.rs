@@ -0,0 +1,275 @@
+use super::Rational;
+
+#[derive(Clone, Copy, Debug, PartialEq)]
+pub enum Relation {
+    Less,    // <=
+    Greater, // >=
+    Equal,
+}
+
+#[derive(Clone, Debug)]
+pub struct Constraint {
+    pub coefficients: Vec<Rational>,
+    pub relation: Relation,
+    pub bound: Rational,
+}
+
+#[derive(Clone, Debug)]
+pub enum Outcome {
+    Optimal(Vec<Rational>, Rational), // Point and the objective value
+    Infeasible,
+    Unbounded,
+}
+
+fn negate(value: Rational) -> Option<Rational> {
+    Rational::integer(0).checked_sub(value)
+}
+
+// Maximizes objective * x subject to the constraints and x >= 0 with the two-phase
+// simplex method in fractions, None if they overflow. Programs here are small,
+// so Bland's rule is used all the way
+pub fn maximize(objective: &[Rational], constraints: &[Constraint]) -> Option<Outcome> {
+    let vars = objective.len();
+    let zero = Rational::integer(0);
+    let one = Rational::integer(1);
+
+    // Bounds are made non-negative, so the slack and artificial variables form the first basis
+    let constraints = constraints
+        .iter()
+        .map(|constraint| {
+            if constraint.bound >= zero {
+                return Some(constraint.clone());
+            }
+            Some(Constraint {
+                coefficients: constraint
+                    .coefficients
+                    .iter()
+                    .map(|&val| negate(val))
+                    .collect::<Option<Vec<Rational>>>()?,
+                relation: match constraint.relation {
+                    Relation::Less => Relation::Greater,
+                    Relation::Greater => Relation::Less,
+                    Relation::Equal => Relation::Equal,
+                },
+                bound: negate(constraint.bound)?,
+            })
+        })
+        .collect::<Option<Vec<Constraint>>>()?;
+
+    let slacks = constraints
+        .iter()
+        .filter(|constraint| constraint.relation != Relation::Equal)
+        .count();
+    let artificials = constraints
+        .iter()
+        .filter(|constraint| constraint.relation != Relation::Less)
+        .count();
+    let width = vars + slacks + artificials + 1;
+
+    let mut tableau = Tableau {
+        rows: Vec::with_capacity(constraints.len() + 1),
+        basis: Vec::with_capacity(constraints.len()),
+    };
+    let (mut slack, mut artificial) = (vars, vars + slacks);
+    for constraint in &constraints {
+        let mut row = vec![zero; width];
+        row[..vars].copy_from_slice(&constraint.coefficients);
+        row[width - 1] = constraint.bound;
+        if constraint.relation != Relation::Equal {
+            row[slack] = match constraint.relation {
+                Relation::Less => one,
+                _ => negate(one)?,
+            };
+            if constraint.relation == Relation::Less {
+                tableau.basis.push(slack);
+            }
+            slack += 1;
+        }
+        if constraint.relation != Relation::Less {
+            row[artificial] = one;
+            tableau.basis.push(artificial);
+            artificial += 1;
+        }
+        tableau.rows.push(row);
+    }
+    tableau.rows.push(vec![zero; width]);
+
+    // Phase 1 maximizes the negated sum of the artificial variables
+    let mut costs = vec![zero; width - 1];
+    costs[vars + slacks..].fill(negate(one)?);
+    tableau.set_objective(&costs)?;
+    tableau.run(width - 1)?;
+    if tableau.value() < zero {
+        return Some(Outcome::Infeasible);
+    }
+
+    // Artificial variables left in the basis at zero are swapped for the real ones where possible
+    for row in 0..constraints.len() {
+        if tableau.basis[row] >= vars + slacks {
+            if let Some(column) = (0..vars + slacks).find(|&j| tableau.rows[row][j] != zero) {
+                tableau.pivot(row, column)?;
+            }
+        }
+    }
+
+    // Phase 2, artificial variables never enter again
+    let mut costs = vec![zero; width - 1];
+    costs[..vars].copy_from_slice(objective);
+    tableau.set_objective(&costs)?;
+    if !tableau.run(vars + slacks)? {
+        return Some(Outcome::Unbounded);
+    }
+
+    let point = (0..vars)
+        .map(|j| {
+            tableau
+                .basis
+                .iter()
+                .position(|&var| var == j)
+                .map_or(zero, |i| tableau.rows[i][width - 1])
+        })
+        .collect();
+    Some(Outcome::Optimal(point, tableau.value()))
+}
+
+// Constraint rows followed by the objective row of reduced costs, the last column is rhs
+struct Tableau {
+    rows: Vec<Vec<Rational>>,
+    basis: Vec<usize>,
+}
+
+impl Tableau {
+    // Replaces the objective row by -costs and eliminates the basic columns from it
+    fn set_objective(&mut self, costs: &[Rational]) -> Option<()> {
+        let last = self.rows.len() - 1;
+        let mut objective = costs
+            .iter()
+            .map(|&cost| negate(cost))
+            .collect::<Option<Vec<Rational>>>()?;
+        objective.push(Rational::integer(0));
+
+        for (row, &var) in self.basis.iter().enumerate() {
+            let factor = objective[var];
+            if factor != Rational::integer(0) {
+                for (val, p) in objective.iter_mut().zip(self.rows[row].iter()) {
+                    *val = val.checked_sub(factor.checked_mul(*p)?)?;
+                }
+            }
+        }
+        self.rows[last] = objective;
+        Some(())
+    }
+
+    // Pivots with Bland's rule over the first `columns` columns,
+    // false if the objective is unbounded
+    fn run(&mut self, columns: usize) -> Option<bool> {
+        let zero = Rational::integer(0);
+        let last = self.rows.len() - 1;
+        let rhs = self.rows[last].len() - 1;
+
+        loop {
+            let column = match (0..columns).find(|&j| self.rows[last][j] < zero) {
+                Some(column) => column,
+                None => return Some(true),
+            };
+
+            let mut leaving: Option<(usize, Rational)> = None;
+            for row in 0..last {
+                let coefficient = self.rows[row][column];
+                if coefficient <= zero {
+                    continue;
+                }
+                let ratio = self.rows[row][rhs].checked_div(coefficient)?;
+                let better = match leaving {
+                    None => true,
+                    Some((other, best)) => {
+                        ratio < best || (ratio == best && self.basis[row] < self.basis[other])
+                    }
+                };
+                if better {
+                    leaving = Some((row, ratio));
+                }
+            }
+            match leaving {
+                Some((row, _)) => self.pivot(row, column)?,
+                None => return Some(false),
+            }
+        }
+    }
+
+    fn pivot(&mut self, row: usize, column: usize) -> Option<()> {
+        let pivot = self.rows[row][column];
+        for val in self.rows[row].iter_mut() {
+            *val = val.checked_div(pivot)?;
+        }
+
+        let pivot_row = self.rows[row].clone();
+        for (i, current) in self.rows.iter_mut().enumerate() {
+            let factor = current[column];
+            if i == row || factor == Rational::integer(0) {
+                continue;
+            }
+            for (val, p) in current.iter_mut().zip(pivot_row.iter()) {
+                *val = val.checked_sub(factor.checked_mul(*p)?)?;
+            }
+        }
+
+        self.basis[row] = column;
+        Some(())
+    }
+
+    // Objective value of the current basis
+    fn value(&self) -> Rational {
+        *self.rows.last().unwrap().last().unwrap()
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn constraint(coefficients: &[i64], relation: Relation, bound: i64) -> Constraint {
+        Constraint {
+            coefficients: coefficients
+                .iter()
+                .copied()
+                .map(Rational::integer)
+                .collect(),
+            relation,
+            bound: Rational::integer(bound),
+        }
+    }
+
+    #[test]
+    fn maximizes_over_the_polygon() {
+        let constraints = [
+            constraint(&[1, 1], Relation::Less, 4),
+            constraint(&[1, 3], Relation::Less, 6),
+            constraint(&[1, 0], Relation::Less, 3),
+        ];
+        match maximize(&[3, 2].map(Rational::integer), &constraints) {
+            Some(Outcome::Optimal(point, value)) => {
+                assert_eq!(point, [3, 1].map(Rational::integer));
+                assert_eq!(value, Rational::integer(11));
+            }
+            outcome => panic!("Unexpected {outcome:?}"),
+        }
+    }
+
+    #[test]
+    fn detects_infeasible_and_unbounded() {
+        let infeasible = [
+            constraint(&[1, 1], Relation::Less, 1),
+            constraint(&[1, 1], Relation::Greater, 2),
+        ];
+        assert!(matches!(
+            maximize(&[1, 1].map(Rational::integer), &infeasible),
+            Some(Outcome::Infeasible)
+        ));
+        let unbounded = [constraint(&[1, -1], Relation::Equal, 0)];
+        assert!(matches!(
+            maximize(&[1, 0].map(Rational::integer), &unbounded),
+            Some(Outcome::Unbounded)
+        ));
+    }
+}
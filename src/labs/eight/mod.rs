@@ -0,0 +1,295 @@
+use std::ops::RangeInclusive;
+
+use eframe::{
+    egui::{
+        Button, CentralPanel, Color32, CtxRef, DragValue, Grid, RichText, ScrollArea, SidePanel,
+        Vec2,
+    },
+    epi::{App, Frame},
+};
+use rand::Rng;
+
+use super::{divider, random_seed, rational::Rational, seeded_rng, SEED_RANGE, VALUE_LIMIT};
+
+mod linear;
+mod solution;
+
+const PLAYERS_RANGE: RangeInclusive<usize> = 2..=6;
+
+// Largest game the nucleolus is computed for, it takes a linear program per tight coalition
+const NUCLEOLUS_LIMIT: usize = 4;
+
+// Random v(S) are up to the step times |S| - 1, so single players get zero
+const VALUE_STEP: i64 = 10;
+
+// Characteristic function form of the cooperative game
+#[derive(Default)]
+struct Game {
+    pub players: usize,
+    pub values: Vec<i64>, // v(S) by the bit mask of the coalition, v({}) = 0
+
+    // Calculation result
+    pub shapley: Option<Vec<Rational>>,
+    pub violation: Option<(usize, usize)>, // Coalitions breaking superadditivity
+    pub least_core: Option<Rational>,      // Smallest possible largest excess v(S) - x(S)
+    pub nucleolus: Option<Vec<Rational>>,
+}
+
+impl Game {
+    // Changes the players count, all values are drawn again
+    pub fn resize(&mut self, players: usize, seed: u64) {
+        self.players = players;
+        self.values = vec![0; 1 << players];
+        self.randomize(seed);
+    }
+
+    // Randomizes values of the coalitions, the same seed always gives the same game
+    pub fn randomize(&mut self, seed: u64) {
+        let mut rng = seeded_rng(seed);
+
+        (1..self.values.len()).for_each(|coalition| {
+            let size = coalition.count_ones() as i64;
+            self.values[coalition] = rng.gen_range(0..=VALUE_STEP * (size - 1));
+        });
+        self.recalc();
+    }
+
+    pub fn clear(&mut self) {
+        self.values.iter_mut().for_each(|val| *val = 0);
+        self.recalc();
+    }
+
+    pub fn recalc(&mut self) {
+        self.shapley = self.shapley();
+        self.violation = self.superadditivity_violation();
+
+        let full = self.players <= NUCLEOLUS_LIMIT;
+        match self.nucleolus(full) {
+            Some((excess, point)) => {
+                self.least_core = Some(excess);
+                self.nucleolus = if full { Some(point) } else { None };
+            }
+            None => {
+                self.least_core = None;
+                self.nucleolus = None;
+            }
+        }
+    }
+
+    // Coalition of all players
+    pub fn grand(&self) -> usize {
+        (1 << self.players) - 1
+    }
+
+    // Payoff of the coalition under the allocation
+    fn share(allocation: &[Rational], coalition: usize) -> Option<Rational> {
+        allocation
+            .iter()
+            .enumerate()
+            .filter(|(i, _)| coalition & (1 << i) != 0)
+            .try_fold(Rational::integer(0), |total, (_, x)| total.checked_add(*x))
+    }
+}
+
+// Players of the coalition numbered from 1, as {1, 3}
+fn coalition_text(coalition: usize, players: usize) -> String {
+    format!(
+        "{{{}}}",
+        (0..players)
+            .filter(|i| coalition & (1 << i) != 0)
+            .map(|i| (i + 1).to_string())
+            .collect::<Vec<String>>()
+            .join(", ")
+    )
+}
+
+fn allocation_text(allocation: &[Rational]) -> String {
+    format!(
+        "({})",
+        allocation
+            .iter()
+            .map(Rational::to_string)
+            .collect::<Vec<String>>()
+            .join(", ")
+    )
+}
+
+// -------------------------------------------------------------------------------------------------
+
+pub struct EighthLab {
+    game: Game,
+    players: usize,
+    seed: u64,
+}
+
+impl App for EighthLab {
+    fn update(&mut self, ctx: &CtxRef, _frame: &Frame) {
+        SidePanel::left("cooperative_left")
+            .max_width(256.0)
+            .show(ctx, |ui| {
+                ui.vertical_centered(|vert| vert.heading("⛭ Controls"));
+                ui.separator();
+                ui.add_sized(
+                    Vec2::new(ui.available_width(), 1.0),
+                    DragValue::new(&mut self.players)
+                        .speed(0.05)
+                        .prefix("Players: ")
+                        .clamp_range(PLAYERS_RANGE),
+                );
+                if ui
+                    .add_sized(
+                        Vec2::new(ui.available_width(), 1.0),
+                        DragValue::new(&mut self.seed)
+                            .speed(0.1)
+                            .prefix("Seed: ")
+                            .clamp_range(SEED_RANGE),
+                    )
+                    .changed()
+                {
+                    self.game.randomize(self.seed);
+                }
+                if ui
+                    .add_sized(
+                        Vec2::new(ui.available_size().x, 1.0),
+                        Button::new("Regenerate"),
+                    )
+                    .clicked()
+                {
+                    self.game.resize(self.players, self.seed);
+                }
+                divider(ui);
+                ui.vertical_centered(|vert| vert.heading("📝 Results"));
+                ui.separator();
+                ui.vertical(|vert| {
+                    match &self.game.shapley {
+                        Some(shapley) => {
+                            vert.label(format!("Shapley value: {}", allocation_text(shapley)))
+                        }
+                        None => vert.label("Shapley value overflows"),
+                    };
+                    match self.game.violation {
+                        None => vert.label("Superadditive"),
+                        Some((first, second)) => vert.label(format!(
+                            "Not superadditive: v({}) < v({}) + v({})",
+                            coalition_text(first | second, self.game.players),
+                            coalition_text(first, self.game.players),
+                            coalition_text(second, self.game.players)
+                        )),
+                    };
+                    vert.separator();
+                    if !self.game.imputations_exist() {
+                        vert.label("No imputations: sum of v({i}) exceeds v(N)");
+                        vert.label("Core is empty");
+                        return;
+                    }
+                    if let Some(excess) = self.game.least_core {
+                        vert.label(format!("Least core excess: {excess}"));
+                        if excess <= Rational::integer(0) {
+                            vert.colored_label(Color32::GREEN, "Core is not empty");
+                        } else {
+                            vert.colored_label(Color32::RED, "Core is empty");
+                        }
+                    }
+                    match &self.game.nucleolus {
+                        Some(nucleolus) => {
+                            vert.label(format!("Nucleolus: {}", allocation_text(nucleolus)))
+                        }
+                        None if self.game.players > NUCLEOLUS_LIMIT => vert.label(format!(
+                            "Nucleolus is computed up to {NUCLEOLUS_LIMIT} players"
+                        )),
+                        None => vert.label("Nucleolus overflows"),
+                    };
+                });
+                divider(ui);
+                ui.vertical_centered(|vert| vert.heading("🔃 Operations"));
+                ui.separator();
+                if ui
+                    .add_sized(
+                        Vec2::new(ui.available_size().x, 1.0),
+                        Button::new("Randomize"),
+                    )
+                    .clicked()
+                {
+                    self.seed = random_seed();
+                    self.game.randomize(self.seed);
+                }
+                if ui
+                    .add_sized(Vec2::new(ui.available_size().x, 1.0), Button::new("Clear"))
+                    .clicked()
+                {
+                    self.game.clear();
+                }
+            });
+
+        CentralPanel::default().show(ctx, |ui| {
+            ScrollArea::vertical().show(ui, |scroll| {
+                let mut changed = false;
+                Grid::new("characteristic_function")
+                    .striped(true)
+                    .show(scroll, |grid| {
+                        // Header
+                        ["S", "v(S)", "Shapley x(S)", "Nucleolus e(S)"]
+                            .iter()
+                            .for_each(|title| {
+                                grid.label(RichText::new(*title).strong());
+                            });
+                        grid.end_row();
+
+                        // Coalitions ordered by size, then by members
+                        let mut coalitions =
+                            (1..1usize << self.game.players).collect::<Vec<usize>>();
+                        coalitions.sort_by_key(|&coalition| {
+                            (coalition.count_ones(), coalition.reverse_bits())
+                        });
+                        coalitions.into_iter().for_each(|coalition| {
+                            grid.label(coalition_text(coalition, self.game.players));
+                            changed |= grid
+                                .add(
+                                    DragValue::new(&mut self.game.values[coalition])
+                                        .speed(0.1)
+                                        .clamp_range(VALUE_LIMIT),
+                                )
+                                .changed();
+
+                            let share = self
+                                .game
+                                .shapley
+                                .as_ref()
+                                .and_then(|shapley| Game::share(shapley, coalition));
+                            grid.label(share.map_or(String::new(), |share| share.to_string()));
+
+                            // Excess of the coalition at the nucleolus, v(S) - x(S)
+                            let excess = self.game.nucleolus.as_ref().and_then(|nucleolus| {
+                                Rational::integer(self.game.values[coalition])
+                                    .checked_sub(Game::share(nucleolus, coalition)?)
+                            });
+                            grid.label(excess.map_or(String::new(), |excess| excess.to_string()));
+                            grid.end_row();
+                        });
+                    });
+                if changed {
+                    self.game.recalc();
+                }
+            });
+        });
+    }
+
+    fn name(&self) -> &str {
+        "Lab 8"
+    }
+}
+
+impl Default for EighthLab {
+    fn default() -> Self {
+        let players = 3;
+        let seed = random_seed();
+        let mut game = Game::default();
+        game.resize(players, seed);
+
+        Self {
+            game,
+            players,
+            seed,
+        }
+    }
+}
@@ -0,0 +1,213 @@
+use super::{
+    linear::{maximize, Constraint, Outcome, Relation},
+    Game, Rational,
+};
+
+// Indices of the players in the coalition
+fn members(coalition: usize, players: usize) -> impl Iterator<Item = usize> {
+    (0..players).filter(move |i| coalition & (1 << i) != 0)
+}
+
+fn factorial(n: usize) -> i64 {
+    (1..=n as i64).product()
+}
+
+impl Game {
+    // Average marginal contribution of each player over all orders of joining:
+    //   phi(i) = sum(|S|! * (n - |S| - 1)! / n! * (v(S + i) - v(S))), i not in S
+    pub fn shapley(&self) -> Option<Vec<Rational>> {
+        let n = self.players;
+        (0..n)
+            .map(|i| {
+                (0..1usize << n)
+                    .filter(|coalition| coalition & (1 << i) == 0)
+                    .try_fold(Rational::integer(0), |total, coalition| {
+                        let size = coalition.count_ones() as usize;
+                        let weight =
+                            Rational::new(factorial(size) * factorial(n - size - 1), factorial(n));
+                        let contribution = Rational::integer(
+                            self.values[coalition | (1 << i)] - self.values[coalition],
+                        );
+                        total.checked_add(weight.checked_mul(contribution)?)
+                    })
+            })
+            .collect()
+    }
+
+    // Disjoint coalitions S and T with v(S + T) < v(S) + v(T)
+    pub fn superadditivity_violation(&self) -> Option<(usize, usize)> {
+        (1..1usize << self.players).find_map(|first| {
+            (first + 1..1usize << self.players)
+                .filter(|second| first & second == 0)
+                .find(|&second| {
+                    self.values[first | second] < self.values[first] + self.values[second]
+                })
+                .map(|second| (first, second))
+        })
+    }
+
+    // Whether the players get at least v({i}) each when sharing v(N)
+    pub fn imputations_exist(&self) -> bool {
+        let total = (0..self.players).map(|i| self.values[1 << i]).sum::<i64>();
+        total <= self.values[self.grand()]
+    }
+
+    // Solves the sequence of linear programs maximizing the smallest surplus
+    // x(S) - v(S) over imputations, the surplus of coalitions found to be the same in every
+    // optimal solution is fixed before the next program. The first program gives the least
+    // core, the last one gives the nucleolus. Only the first one is solved unless `full`.
+    // Returns the least core excess max(v(S) - x(S)) and the found imputation,
+    // None if imputations don't exist or fractions overflow
+    pub fn nucleolus(&self, full: bool) -> Option<(Rational, Vec<Rational>)> {
+        if !self.imputations_exist() {
+            return None;
+        }
+        let n = self.players;
+        let zero = Rational::integer(0);
+        let one = Rational::integer(1);
+        let minus_one = zero.checked_sub(one)?;
+
+        // Variables are y(i) = x(i) - v({i}) >= 0 and e1 - e2, the smallest surplus
+        let singletons = |coalition: usize| {
+            members(coalition, n)
+                .map(|i| self.values[1 << i])
+                .sum::<i64>()
+        };
+        let row = |coalition: usize, epsilon: Rational| {
+            let mut coefficients = (0..n)
+                .map(|i| Rational::integer(i64::from(coalition & (1 << i) != 0)))
+                .collect::<Vec<Rational>>();
+            coefficients.extend([epsilon, zero.checked_sub(epsilon).unwrap()]);
+            coefficients
+        };
+        let bound =
+            |coalition: usize| Rational::integer(self.values[coalition] - singletons(coalition));
+
+        let coalitions = (1..self.grand()).collect::<Vec<usize>>();
+        let mut fixed = vec![None::<Rational>; self.grand()];
+        let mut least_core = None;
+        let mut point = None;
+
+        loop {
+            let unfixed = coalitions
+                .iter()
+                .copied()
+                .filter(|&coalition| fixed[coalition].is_none())
+                .collect::<Vec<usize>>();
+            if unfixed.is_empty() {
+                break;
+            }
+
+            let mut constraints = vec![Constraint {
+                coefficients: row(self.grand(), zero),
+                relation: Relation::Equal,
+                bound: bound(self.grand()),
+            }];
+            coalitions.iter().for_each(|&coalition| {
+                constraints.push(match fixed[coalition] {
+                    Some(surplus) => Constraint {
+                        coefficients: row(coalition, zero),
+                        relation: Relation::Equal,
+                        bound: bound(coalition).checked_add(surplus).unwrap(),
+                    },
+                    None => Constraint {
+                        coefficients: row(coalition, minus_one),
+                        relation: Relation::Greater,
+                        bound: bound(coalition),
+                    },
+                })
+            });
+
+            let mut objective = vec![zero; n];
+            objective.extend([one, minus_one]);
+            let (solution, surplus) = match maximize(&objective, &constraints)? {
+                Outcome::Optimal(solution, surplus) => (solution, surplus),
+                _ => return None,
+            };
+            least_core.get_or_insert(surplus);
+            point = Some(
+                (0..n)
+                    .map(|i| solution[i].checked_add(Rational::integer(self.values[1 << i])))
+                    .collect::<Option<Vec<Rational>>>()?,
+            );
+            if !full {
+                break;
+            }
+
+            // Surplus of the coalition is the smallest one in every optimal solution
+            // if even its largest value doesn't exceed it
+            constraints.push(Constraint {
+                coefficients: [vec![zero; n], vec![one, minus_one]].concat(),
+                relation: Relation::Equal,
+                bound: surplus,
+            });
+            let mut progress = false;
+            for coalition in unfixed {
+                let current = members(coalition, n)
+                    .try_fold(zero, |total, i| total.checked_add(solution[i]))?;
+                if current.checked_sub(bound(coalition))? != surplus {
+                    continue;
+                }
+                if let Outcome::Optimal(_, highest) = maximize(&row(coalition, zero), &constraints)?
+                {
+                    if highest.checked_sub(bound(coalition))? == surplus {
+                        fixed[coalition] = Some(surplus);
+                        progress = true;
+                    }
+                }
+            }
+            if !progress {
+                break;
+            }
+        }
+
+        Some((zero.checked_sub(least_core?)?, point?))
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn game(values: Vec<i64>) -> Game {
+        Game {
+            players: values.len().trailing_zeros() as usize,
+            values,
+            ..Default::default()
+        }
+    }
+
+    #[test]
+    fn solves_glove_game() {
+        // The first player has the left glove, the others have right ones
+        let game = game(vec![0, 0, 0, 1, 0, 1, 0, 1]);
+        assert_eq!(
+            game.shapley().unwrap(),
+            [
+                Rational::new(2, 3),
+                Rational::new(1, 6),
+                Rational::new(1, 6)
+            ]
+        );
+        let (excess, nucleolus) = game.nucleolus(true).unwrap();
+        assert_eq!(excess, Rational::integer(0));
+        assert_eq!(nucleolus, [1, 0, 0].map(Rational::integer));
+        assert_eq!(game.superadditivity_violation(), None);
+    }
+
+    #[test]
+    fn divides_estate_by_talmud() {
+        // Estate of 300 and claims of 100, 200 and 300, v(S) is left after paying the others
+        let game = game(vec![0, 0, 0, 0, 0, 100, 200, 300]);
+        let (_, nucleolus) = game.nucleolus(true).unwrap();
+        assert_eq!(nucleolus, [50, 100, 150].map(Rational::integer));
+    }
+
+    #[test]
+    fn finds_no_imputations() {
+        let game = game(vec![0, 10, 10, 15]);
+        assert!(!game.imputations_exist());
+        assert_eq!(game.nucleolus(true), None);
+        assert_eq!(game.superadditivity_violation(), Some((1, 2)));
+    }
+}
@@ -0,0 +1,149 @@
+use std::{cell::RefCell, rc::Rc};
+
+use eframe::epi::Frame;
+
+type Loaded = Option<Result<String, String>>;
+
+// Text file chosen by the user, on the web it arrives asynchronously
+#[derive(Default)]
+pub struct Upload {
+    loaded: Rc<RefCell<Loaded>>,
+}
+
+impl Upload {
+    // Asks the user for a file with one of the extensions
+    #[cfg(not(target_arch = "wasm32"))]
+    pub fn open(&self, _frame: &Frame, extensions: &[&str]) {
+        let patterns = extensions
+            .iter()
+            .map(|ext| format!("*.{ext}"))
+            .collect::<Vec<String>>();
+        let patterns = patterns.iter().map(String::as_str).collect::<Vec<&str>>();
+
+        if let Some(path) = tinyfiledialogs::open_file_dialog(
+            "Open",
+            "",
+            Some((&patterns, extensions.join(", ").as_str())),
+        ) {
+            *self.loaded.borrow_mut() =
+                Some(std::fs::read_to_string(&path).map_err(|err| format!("{path}: {err}")));
+        }
+    }
+
+    // Asks the user for a file with one of the extensions
+    #[cfg(target_arch = "wasm32")]
+    pub fn open(&self, frame: &Frame, extensions: &[&str]) {
+        use wasm_bindgen::{closure::Closure, JsCast};
+        use web_sys::{FileReader, HtmlInputElement};
+
+        let input = match web_sys::window()
+            .and_then(|window| window.document())
+            .and_then(|document| document.create_element("input").ok())
+            .and_then(|element| element.dyn_into::<HtmlInputElement>().ok())
+        {
+            Some(input) => input,
+            None => return,
+        };
+        input.set_type("file");
+        input.set_accept(
+            &extensions
+                .iter()
+                .map(|ext| format!(".{ext}"))
+                .collect::<Vec<String>>()
+                .join(","),
+        );
+
+        let loaded = self.loaded.clone();
+        let frame = frame.clone();
+        let on_change = Closure::once(move |event: web_sys::Event| {
+            let file = event
+                .target()
+                .and_then(|target| target.dyn_into::<HtmlInputElement>().ok())
+                .and_then(|input| input.files())
+                .and_then(|files| files.get(0));
+            let (file, reader) = match (file, FileReader::new().ok()) {
+                (Some(file), Some(reader)) => (file, reader),
+                _ => return,
+            };
+
+            let on_load = Closure::once(move |event: web_sys::Event| {
+                let text = event
+                    .target()
+                    .and_then(|target| target.dyn_into::<FileReader>().ok())
+                    .and_then(|reader| reader.result().ok())
+                    .and_then(|result| result.as_string());
+                *loaded.borrow_mut() =
+                    Some(text.ok_or_else(|| "Unable to read the file".to_string()));
+                frame.request_repaint();
+            });
+            reader.set_onload(Some(on_load.as_ref().unchecked_ref()));
+            on_load.forget();
+            let _ = reader.read_as_text(&file);
+        });
+        input.set_onchange(Some(on_change.as_ref().unchecked_ref()));
+        on_change.forget();
+
+        input.click();
+    }
+
+    // Takes the loaded content out, if there is one
+    pub fn take(&self) -> Loaded {
+        self.loaded.borrow_mut().take()
+    }
+}
+
+// Saves text to the file chosen by the user, returns false if the user cancelled saving
+#[cfg(not(target_arch = "wasm32"))]
+pub fn save(name: &str, content: &str) -> Result<bool, String> {
+    let extension = name.rsplit('.').next().unwrap_or_default();
+
+    match tinyfiledialogs::save_file_dialog_with_filter(
+        "Save",
+        name,
+        &[format!("*.{extension}").as_str()],
+        extension,
+    ) {
+        Some(path) => std::fs::write(&path, content)
+            .map(|_| true)
+            .map_err(|err| format!("{path}: {err}")),
+        None => Ok(false),
+    }
+}
+
+// Downloads text as a file
+#[cfg(target_arch = "wasm32")]
+pub fn save(name: &str, content: &str) -> Result<bool, String> {
+    use wasm_bindgen::{closure::Closure, JsCast};
+    use web_sys::{Blob, BlobPropertyBag, HtmlAnchorElement, Url};
+
+    let error = |_| "Unable to download the file".to_string();
+
+    let parts = js_sys::Array::of1(&content.into());
+    let options = BlobPropertyBag::new();
+    options.set_type("text/plain;charset=utf-8");
+    let blob = Blob::new_with_str_sequence_and_options(&parts, &options).map_err(error)?;
+    let url = Url::create_object_url_with_blob(&blob).map_err(error)?;
+
+    let window = web_sys::window().ok_or_else(|| "Unable to download the file".to_string())?;
+    let anchor = window
+        .document()
+        .and_then(|document| document.create_element("a").ok())
+        .and_then(|element| element.dyn_into::<HtmlAnchorElement>().ok())
+        .ok_or_else(|| "Unable to download the file".to_string())?;
+    anchor.set_href(&url);
+    anchor.set_download(name);
+    anchor.click();
+
+    // The download starts asynchronously, so the URL is revoked a second later
+    let revoke = Closure::once(move || {
+        let _ = Url::revoke_object_url(&url);
+    });
+    window
+        .set_timeout_with_callback_and_timeout_and_arguments_0(
+            revoke.as_ref().unchecked_ref(),
+            1000,
+        )
+        .map_err(error)?;
+    revoke.forget();
+    Ok(true)
+}
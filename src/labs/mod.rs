@@ -1,13 +1,29 @@
+use std::ops::RangeInclusive;
+
 use eframe::{
-    egui::{global_dark_light_mode_switch, CtxRef, TopBottomPanel, Ui, Vec2},
+    egui::{global_dark_light_mode_switch, CtxRef, SelectableLabel, TopBottomPanel, Ui, Vec2},
     epi::{App as EApp, Frame},
 };
+use rand::{thread_rng, Rng, SeedableRng};
+use rand_chacha::ChaCha8Rng;
 
+use eight::EighthLab;
 use seven::SeventhLab;
+pub use six::solve;
 use six::SixthLab;
 
+mod eight;
+mod file;
+mod rational;
 mod seven;
 mod six;
+mod table;
+
+// Seeds are kept short, so variants are easy to share
+const SEED_RANGE: RangeInclusive<u64> = 0..=999_999;
+
+// Bounds of the payoffs and coalition values entered by hand
+const VALUE_LIMIT: RangeInclusive<i64> = -1000..=1000;
 
 #[inline]
 fn divider(ui: &mut Ui) {
@@ -15,12 +31,49 @@ fn divider(ui: &mut Ui) {
     ui.add_space(16.0);
 }
 
+// Full width label opening and closing the viewer window
+fn viewer_toggle(ui: &mut Ui, open: &mut bool, label: &str) {
+    if ui
+        .add_sized(
+            Vec2::new(ui.available_width(), 1.0),
+            SelectableLabel::new(*open, label),
+        )
+        .clicked()
+    {
+        *open = !*open;
+    }
+}
+
+// Generator giving the same sequence for the same seed on native and wasm
+fn seeded_rng(seed: u64) -> ChaCha8Rng {
+    ChaCha8Rng::seed_from_u64(seed)
+}
+
+// Seed of a new random variant
+fn random_seed() -> u64 {
+    thread_rng().gen_range(SEED_RANGE)
+}
+
+// Milliseconds of the wall clock, std::time panics on wasm
+#[cfg(target_arch = "wasm32")]
+fn now() -> f64 {
+    js_sys::Date::now()
+}
+
+#[cfg(not(target_arch = "wasm32"))]
+fn now() -> f64 {
+    std::time::SystemTime::now()
+        .duration_since(std::time::UNIX_EPOCH)
+        .map_or(0.0, |time| time.as_secs_f64() * 1000.0)
+}
+
 // -------------------------------------------------------------------------------------------------
 
 #[derive(PartialEq)]
 enum Anchor {
     Sixth,
     Seventh,
+    Eighth,
 }
 
 impl Anchor {
@@ -28,10 +81,12 @@ impl Anchor {
         match self {
             Anchor::Sixth => "Lab 6",
             Anchor::Seventh => "Lab 7",
+            Anchor::Eighth => "Lab 8",
         }
     }
 }
 
+#[allow(clippy::derivable_impls)]
 impl Default for Anchor {
     fn default() -> Self {
         Anchor::Sixth
@@ -44,6 +99,7 @@ impl Default for Anchor {
 struct Labs {
     sixth_lab: SixthLab,
     seventh_lab: SeventhLab,
+    eighth_lab: EighthLab,
 }
 
 impl Labs {
@@ -51,6 +107,7 @@ impl Labs {
         vec![
             (Anchor::Sixth, &mut self.sixth_lab as &mut dyn EApp),
             (Anchor::Seventh, &mut self.seventh_lab as &mut dyn EApp),
+            (Anchor::Eighth, &mut self.eighth_lab as &mut dyn EApp),
         ]
         .into_iter()
     }
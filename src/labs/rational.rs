@@ -0,0 +1,267 @@
+use std::{cmp::Ordering, fmt, str::FromStr};
+
+use serde::{Serialize, Serializer};
+
+// Exact fraction, always stored reduced with a positive denominator
+#[derive(Clone, Copy, Debug, PartialEq, Eq, Hash)]
+pub struct Rational {
+    num: i64,
+    den: i64,
+}
+
+impl Rational {
+    // Largest denominator used when approximating floats
+    const MAX_DENOMINATOR: i64 = 1_000_000;
+    // Largest number of continued fraction terms, floats need far fewer
+    const MAX_TERMS: usize = 64;
+
+    // Fraction of trusted values, panics if it doesn't fit like -1/i64::MIN.
+    // Parsed values go through `checked_new`
+    pub fn new(num: i64, den: i64) -> Self {
+        assert!(den != 0, "Rational with zero denominator");
+        Self::checked_new(num, den).expect("Rational out of range")
+    }
+
+    // None on zero denominator or if the reduced fraction doesn't fit
+    pub fn checked_new(num: i64, den: i64) -> Option<Self> {
+        Self::from_i128(num as i128, den as i128)
+    }
+
+    pub fn integer(num: i64) -> Self {
+        Self { num, den: 1 }
+    }
+
+    pub fn numer(&self) -> i64 {
+        self.num
+    }
+
+    pub fn denom(&self) -> i64 {
+        self.den
+    }
+
+    pub fn to_f64(self) -> f64 {
+        self.num as f64 / self.den as f64
+    }
+
+    // Closest fraction with a bounded denominator (continued fractions),
+    // non-finite values have none and are taken as zero
+    pub fn from_f64(value: f64) -> Self {
+        if !value.is_finite() {
+            return Self::integer(0);
+        }
+
+        let (mut h0, mut h1) = (0i64, 1i64);
+        let (mut k0, mut k1) = (1i64, 0i64);
+        let mut x = value;
+
+        for _ in 0..Self::MAX_TERMS {
+            let a = x.floor();
+            if a.abs() > i64::MAX as f64 {
+                break;
+            }
+            let a = a as i64;
+
+            let k2 = match a.checked_mul(k1).and_then(|v| v.checked_add(k0)) {
+                Some(k2) if k2 <= Self::MAX_DENOMINATOR => k2,
+                _ => break,
+            };
+            let h2 = match a.checked_mul(h1).and_then(|v| v.checked_add(h0)) {
+                Some(h2) => h2,
+                None => break,
+            };
+            (h0, h1, k0, k1) = (h1, h2, k1, k2);
+
+            let fract = x - a as f64;
+            if fract.abs() < 1e-12 || (h1 as f64 / k1 as f64 - value).abs() < 1e-12 {
+                break;
+            }
+            x = 1.0 / fract;
+        }
+
+        if k1 == 0 {
+            Self::integer(value.round() as i64)
+        } else {
+            Self::new(h1, k1)
+        }
+    }
+
+    // Reduces the fraction computed with wide intermediates, None if it doesn't fit
+    fn from_i128(num: i128, den: i128) -> Option<Self> {
+        if den == 0 {
+            return None;
+        }
+        let gcd = gcd_i128(num, den).max(1);
+        let sign = if den < 0 { -1 } else { 1 };
+        Some(Self {
+            num: i64::try_from(sign * num / gcd).ok()?,
+            den: i64::try_from(sign * den / gcd).ok()?,
+        })
+    }
+
+    // Products of two i64 always fit i128, their sum may not
+    pub fn checked_add(self, other: Self) -> Option<Self> {
+        let (a, b) = (self.wide(), other.wide());
+        Self::from_i128((a.0 * b.1).checked_add(b.0 * a.1)?, a.1 * b.1)
+    }
+
+    pub fn checked_sub(self, other: Self) -> Option<Self> {
+        let (a, b) = (self.wide(), other.wide());
+        Self::from_i128((a.0 * b.1).checked_sub(b.0 * a.1)?, a.1 * b.1)
+    }
+
+    pub fn checked_mul(self, other: Self) -> Option<Self> {
+        let (a, b) = (self.wide(), other.wide());
+        Self::from_i128(a.0 * b.0, a.1 * b.1)
+    }
+
+    // None on division by zero as well
+    pub fn checked_div(self, other: Self) -> Option<Self> {
+        let (a, b) = (self.wide(), other.wide());
+        Self::from_i128(a.0 * b.1, a.1 * b.0)
+    }
+
+    fn wide(self) -> (i128, i128) {
+        (self.num as i128, self.den as i128)
+    }
+}
+
+impl Ord for Rational {
+    fn cmp(&self, other: &Self) -> Ordering {
+        (self.num as i128 * other.den as i128).cmp(&(other.num as i128 * self.den as i128))
+    }
+}
+
+impl PartialOrd for Rational {
+    fn partial_cmp(&self, other: &Self) -> Option<Ordering> {
+        Some(self.cmp(other))
+    }
+}
+
+impl fmt::Display for Rational {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        if self.den == 1 {
+            write!(f, "{}", self.num)
+        } else {
+            write!(f, "{}/{}", self.num, self.den)
+        }
+    }
+}
+
+// Written as "a/b", so exact solutions survive JSON export
+impl Serialize for Rational {
+    fn serialize<S: Serializer>(&self, serializer: S) -> Result<S::Ok, S::Error> {
+        serializer.collect_str(self)
+    }
+}
+
+// Accepts "a/b", integers and finite decimals
+impl FromStr for Rational {
+    type Err = String;
+
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        let invalid = || format!("Invalid fraction \"{s}\"");
+
+        if let Some((num, den)) = s.split_once('/') {
+            let num = num.trim().parse::<i64>().map_err(|_| invalid())?;
+            let den = den.trim().parse::<i64>().map_err(|_| invalid())?;
+            if den == 0 {
+                return Err(format!("Zero denominator in \"{s}\""));
+            }
+            Self::checked_new(num, den).ok_or_else(|| format!("Fraction \"{s}\" is out of range"))
+        } else if let Some((int, fract)) = s.split_once('.') {
+            if fract.is_empty() || !fract.chars().all(|c| c.is_ascii_digit()) || fract.len() > 18 {
+                return Err(invalid());
+            }
+            let den = 10i64.pow(fract.len() as u32);
+            let negative = int.starts_with('-');
+            let int = match int {
+                "" | "-" | "+" => 0,
+                int => int.parse::<i64>().map_err(|_| invalid())?,
+            };
+            let fract = fract.parse::<i64>().map_err(|_| invalid())?;
+            let num = int
+                .checked_mul(den)
+                .and_then(|num| {
+                    if negative {
+                        num.checked_sub(fract)
+                    } else {
+                        num.checked_add(fract)
+                    }
+                })
+                .ok_or_else(invalid)?;
+            Self::checked_new(num, den).ok_or_else(invalid)
+        } else {
+            s.parse::<i64>().map(Self::integer).map_err(|_| invalid())
+        }
+    }
+}
+
+fn gcd_i128(mut a: i128, mut b: i128) -> i128 {
+    while b != 0 {
+        (a, b) = (b, a % b);
+    }
+    a.abs()
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn reduces_with_positive_denominator() {
+        let val = Rational::new(6, -4);
+        assert_eq!((val.numer(), val.denom()), (-3, 2));
+        assert_eq!(Rational::new(0, -5), Rational::integer(0));
+    }
+
+    #[test]
+    fn parses_fractions_and_decimals() {
+        assert_eq!("3/6".parse::<Rational>(), Ok(Rational::new(1, 2)));
+        assert_eq!("-1.25".parse::<Rational>(), Ok(Rational::new(-5, 4)));
+        assert_eq!("7".parse::<Rational>(), Ok(Rational::integer(7)));
+        assert!("1/0".parse::<Rational>().is_err());
+        assert!("a/b".parse::<Rational>().is_err());
+    }
+
+    #[test]
+    fn parses_extreme_denominators() {
+        assert_eq!(
+            "0/-9223372036854775808".parse::<Rational>(),
+            Ok(Rational::integer(0))
+        );
+        assert_eq!(
+            "-9223372036854775808/-9223372036854775808".parse::<Rational>(),
+            Ok(Rational::integer(1))
+        );
+        assert!("1/-9223372036854775808".parse::<Rational>().is_err());
+    }
+
+    #[test]
+    fn checked_operations() {
+        let half = Rational::new(1, 2);
+        let third = Rational::new(1, 3);
+        assert_eq!(half.checked_add(third), Some(Rational::new(5, 6)));
+        assert_eq!(half.checked_sub(third), Some(Rational::new(1, 6)));
+        assert_eq!(half.checked_mul(third), Some(Rational::new(1, 6)));
+        assert_eq!(half.checked_div(third), Some(Rational::new(3, 2)));
+        assert_eq!(half.checked_div(Rational::integer(0)), None);
+
+        let min = Rational::integer(i64::MIN);
+        assert_eq!(min.checked_add(min), None);
+        assert_eq!(min.checked_mul(Rational::integer(-1)), None);
+    }
+
+    #[test]
+    fn approximates_floats() {
+        assert_eq!(Rational::from_f64(0.75), Rational::new(3, 4));
+        assert_eq!(Rational::from_f64(1.0 / 3.0), Rational::new(1, 3));
+        assert_eq!(Rational::from_f64(-2.0), Rational::integer(-2));
+    }
+
+    #[test]
+    fn approximates_non_finite_floats_as_zero() {
+        assert_eq!(Rational::from_f64(f64::NAN), Rational::integer(0));
+        assert_eq!(Rational::from_f64(f64::INFINITY), Rational::integer(0));
+        assert_eq!(Rational::from_f64(f64::NEG_INFINITY), Rational::integer(0));
+    }
+}
@@ -3,21 +3,27 @@ use std::ops::RangeInclusive;
 use eframe::{
     egui::{
         plot::{Legend, Line, Plot, Points, Value, Values},
-        Button, CentralPanel, Color32, CtxRef, DragValue, Grid, RichText, ScrollArea,
-        SelectableLabel, SidePanel, Vec2, Window,
+        Button, CentralPanel, Color32, CtxRef, DragValue, Grid, RichText, ScrollArea, SidePanel,
+        Vec2, Window,
     },
     epi::{App, Frame},
 };
-use rand::{thread_rng, Rng};
+use rand::Rng;
 
-use super::divider;
+use super::{divider, random_seed, seeded_rng, viewer_toggle, SEED_RANGE};
 
 const NODES_RANGE: RangeInclusive<usize> = 1..=15;
 
+// Arrowhead sides length and angle to the arc, the tip stops short of the vertex point
+const ARROW_LENGTH: f64 = 0.12;
+const ARROW_ANGLE: f64 = 0.4;
+const ARROW_OFFSET: f64 = 0.06;
+
 #[derive(Default)]
 struct Matrix {
-    inner: Vec<Vec<bool>>,
+    inner: Vec<Vec<bool>>, // Lower-triangular for undirected graphs, full for directed ones
     vertices: usize,
+    directed: bool, // Whether inner[i][j] is the arc from i to j
 }
 
 impl Matrix {
@@ -28,13 +34,42 @@ impl Matrix {
         self.inner = Vec::with_capacity(self.vertices);
 
         for i in 0..self.vertices {
-            self.inner.push(vec![false; i + 1]);
+            self.inner.push(vec![
+                false;
+                if self.directed { self.vertices } else { i + 1 }
+            ]);
         }
     }
 
-    // Randomizes edges of the graph
-    pub fn randomize(&mut self) {
-        let mut rng = thread_rng();
+    // Switches between directed and undirected graphs keeping the edges,
+    // an undirected edge becomes two opposite arcs and opposite arcs merge into one edge
+    pub fn set_directed(&mut self, directed: bool) {
+        if self.directed == directed {
+            return;
+        }
+        let edges = self.edges();
+        self.directed = directed;
+        self.resize(self.vertices);
+        edges.into_iter().for_each(|(i, j)| {
+            *self.edge_mut(i, j) = true;
+            if directed {
+                *self.edge_mut(j, i) = true;
+            }
+        });
+    }
+
+    // State of the edge between i and j, the arc from i to j for directed graphs
+    pub fn edge_mut(&mut self, i: usize, j: usize) -> &mut bool {
+        if self.directed || j <= i {
+            &mut self.inner[i][j]
+        } else {
+            &mut self.inner[j][i]
+        }
+    }
+
+    // Randomizes edges of the graph, the same seed always gives the same graph
+    pub fn randomize(&mut self, seed: u64) {
+        let mut rng = seeded_rng(seed);
 
         self.inner.iter_mut().enumerate().for_each(|(i, row)| {
             row.iter_mut().enumerate().for_each(|(j, state)| {
@@ -80,27 +115,42 @@ impl Matrix {
         })
     }
 
-    // Outputs edges
+    // Outputs edges, directed ones as (from, to) pairs
     fn edges(&self) -> Vec<(usize, usize)> {
         self.inner
             .iter()
             .enumerate()
-            .map(|(i, row)| {
+            .flat_map(|(i, row)| {
                 row.iter()
                     .enumerate()
-                    .filter_map(|(j, state)| if *state { Some((i, j)) } else { None })
-                    .collect::<Vec<(usize, usize)>>()
+                    .filter_map(move |(j, state)| if *state { Some((i, j)) } else { None })
             })
-            .flatten()
             .collect()
     }
 }
 
+// Arrowhead sides of the arc from `from` to `to` as a polyline through the tip
+fn arrowhead(from: Value, to: Value) -> Vec<Value> {
+    let angle = (to.y - from.y).atan2(to.x - from.x);
+    let tip = Value::new(
+        to.x - ARROW_OFFSET * angle.cos(),
+        to.y - ARROW_OFFSET * angle.sin(),
+    );
+    let side = |turn: f64| {
+        Value::new(
+            tip.x - ARROW_LENGTH * (angle + turn).cos(),
+            tip.y - ARROW_LENGTH * (angle + turn).sin(),
+        )
+    };
+    vec![side(ARROW_ANGLE), tip, side(-ARROW_ANGLE)]
+}
+
 // -------------------------------------------------------------------------------------------------
 
 pub struct SeventhLab {
     matrix: Matrix,
     nodes: usize,
+    seed: u64,
 
     // UI
     matrix_viewer: bool,
@@ -121,6 +171,22 @@ impl App for SeventhLab {
                         .prefix("Nodes: ")
                         .clamp_range(NODES_RANGE),
                 );
+                let mut directed = self.matrix.directed;
+                if ui.checkbox(&mut directed, "Directed").changed() {
+                    self.matrix.set_directed(directed);
+                }
+                if ui
+                    .add_sized(
+                        Vec2::new(ui.available_width(), 1.0),
+                        DragValue::new(&mut self.seed)
+                            .speed(0.1)
+                            .prefix("Seed: ")
+                            .clamp_range(SEED_RANGE),
+                    )
+                    .changed()
+                {
+                    self.matrix.randomize(self.seed);
+                }
                 if ui
                     .add_sized(
                         Vec2::new(ui.available_size().x, 1.0),
@@ -134,23 +200,8 @@ impl App for SeventhLab {
                 ui.vertical_centered(|vert| vert.heading("👁 Viewers"));
                 ui.separator();
                 ui.vertical(|vert| {
-                    let matrix_viewer_label =
-                        SelectableLabel::new(self.matrix_viewer, "Matrix Viewer");
-                    let graph_viewer_label =
-                        SelectableLabel::new(self.graph_viewer, "Graph Viewer");
-
-                    if vert
-                        .add_sized(Vec2::new(vert.available_width(), 1.0), matrix_viewer_label)
-                        .clicked()
-                    {
-                        self.matrix_viewer = !self.matrix_viewer;
-                    };
-                    if vert
-                        .add_sized(Vec2::new(vert.available_width(), 1.0), graph_viewer_label)
-                        .clicked()
-                    {
-                        self.graph_viewer = !self.graph_viewer;
-                    };
+                    viewer_toggle(vert, &mut self.matrix_viewer, "Matrix Viewer");
+                    viewer_toggle(vert, &mut self.graph_viewer, "Graph Viewer");
                 });
                 divider(ui);
                 ui.vertical_centered(|vert| vert.heading("🔃 Operations"));
@@ -162,7 +213,8 @@ impl App for SeventhLab {
                     )
                     .clicked()
                 {
-                    self.matrix.randomize();
+                    self.seed = random_seed();
+                    self.matrix.randomize(self.seed);
                 }
                 if ui
                     .add_sized(Vec2::new(ui.available_size().x, 1.0), Button::new("Fill"))
@@ -197,19 +249,10 @@ impl App for SeventhLab {
                             for i in 0..self.matrix.vertices {
                                 grid.label(RichText::new((i + 1).to_string()).strong());
                                 for j in 0..self.matrix.vertices {
-                                    // Performance preferred in this case
-                                    #[allow(clippy::comparison_chain)]
                                     if j == i {
                                         grid.label("-");
                                     } else {
-                                        grid.checkbox(
-                                            if j < i {
-                                                &mut self.matrix.inner[i][j]
-                                            } else {
-                                                &mut self.matrix.inner[j][i]
-                                            },
-                                            "",
-                                        );
+                                        grid.checkbox(self.matrix.edge_mut(i, j), "");
                                     }
                                 }
                                 grid.end_row();
@@ -245,6 +288,17 @@ impl App for SeventhLab {
                                     .color(Color32::GREEN)
                                     .name("Edges"),
                                 );
+                                // Arrowheads share the legend entry, so they are hidden with the edges
+                                if self.matrix.directed {
+                                    plot.line(
+                                        Line::new(Values::from_values(arrowhead(
+                                            vertices[*a],
+                                            vertices[*b],
+                                        )))
+                                        .color(Color32::GREEN)
+                                        .name("Edges"),
+                                    );
+                                }
                             });
                         }
                     });
@@ -269,8 +323,40 @@ impl Default for SeventhLab {
         Self {
             matrix,
             nodes,
+            seed: random_seed(),
             matrix_viewer: true,
             graph_viewer: true,
         }
     }
 }
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn converts_edges_to_arcs_and_back() {
+        let mut matrix = Matrix::default();
+        matrix.resize(3);
+        *matrix.edge_mut(0, 2) = true;
+        assert_eq!(matrix.edges(), [(2, 0)]);
+
+        matrix.set_directed(true);
+        assert_eq!(matrix.edges(), [(0, 2), (2, 0)]);
+
+        *matrix.edge_mut(1, 0) = true;
+        matrix.set_directed(false);
+        assert_eq!(matrix.edges(), [(1, 0), (2, 0)]);
+    }
+
+    #[test]
+    fn same_seed_gives_same_graph() {
+        let mut first = Matrix::default();
+        first.resize(6);
+        first.randomize(42);
+        let mut second = Matrix::default();
+        second.resize(6);
+        second.randomize(42);
+        assert_eq!(first.edges(), second.edges());
+    }
+}
@@ -0,0 +1,250 @@
+use super::StoreType;
+
+// Tolerance used in the equilibrium conditions
+const EPS: f64 = 1e-9;
+
+// Nash equilibrium in mixed strategies
+#[derive(Clone, Debug)]
+pub struct Equilibrium {
+    pub a: Vec<f64>,
+    pub b: Vec<f64>,
+    pub payoff: (f64, f64), // Expected payoffs of A and B
+}
+
+impl Equilibrium {
+    // Cell of the equilibrium if both strategies are pure
+    pub fn pure(&self) -> Option<(usize, usize)> {
+        let pure = |probabilities: &[f64]| probabilities.iter().position(|p| (p - 1.0).abs() < EPS);
+        pure(&self.a).zip(pure(&self.b))
+    }
+}
+
+// Solution of the non-zero-sum game given by payoff matrices of A and B
+#[derive(Clone, Debug, Default)]
+pub struct Bimatrix {
+    pub equilibria: Vec<Equilibrium>,
+    pub pareto: Vec<(usize, usize)>, // Pareto-optimal outcomes
+}
+
+impl Bimatrix {
+    // Enumerates equilibria over all pairs of supports of equal size,
+    // so all equilibria of nondegenerate games are found
+    pub fn solve(a: &[Vec<StoreType>], b: &[Vec<StoreType>]) -> Self {
+        let rows = a.len();
+        let columns = a.first().map_or(0, Vec::len);
+
+        let a = a
+            .iter()
+            .map(|row| row.iter().map(|val| val.to_f64()).collect())
+            .collect::<Vec<Vec<f64>>>();
+        let b = b
+            .iter()
+            .map(|row| row.iter().map(|val| val.to_f64()).collect())
+            .collect::<Vec<Vec<f64>>>();
+
+        let mut equilibria: Vec<Equilibrium> = Vec::new();
+        for size in 1..=rows.min(columns) {
+            let a_supports = subsets(rows, size);
+            let b_supports = subsets(columns, size);
+
+            a_supports.iter().for_each(|a_support| {
+                b_supports.iter().for_each(|b_support| {
+                    if let Some(equilibrium) = Self::check(&a, &b, a_support, b_support) {
+                        if !equilibria.iter().any(|other| {
+                            same(&other.a, &equilibrium.a) && same(&other.b, &equilibrium.b)
+                        }) {
+                            equilibria.push(equilibrium);
+                        }
+                    }
+                })
+            });
+        }
+
+        let mut pareto = Vec::new();
+        (0..rows).for_each(|i| {
+            (0..columns).for_each(|j| {
+                let dominated = (0..rows).any(|k| {
+                    (0..columns).any(|l| {
+                        a[k][l] >= a[i][j]
+                            && b[k][l] >= b[i][j]
+                            && (a[k][l] > a[i][j] || b[k][l] > b[i][j])
+                    })
+                });
+                if !dominated {
+                    pareto.push((i, j));
+                }
+            })
+        });
+
+        Self { equilibria, pareto }
+    }
+
+    // Finds strategies on the given supports making the opponent indifferent
+    fn check(
+        a: &[Vec<f64>],
+        b: &[Vec<f64>],
+        a_support: &[usize],
+        b_support: &[usize],
+    ) -> Option<Equilibrium> {
+        let rows = a.len();
+        let columns = a[0].len();
+
+        // B mixes over its support so that all A strategies in the support give the same payoff,
+        // no A strategy outside of the support may be better
+        let (y, u) = indifference(a_support.len(), |k, l| a[a_support[k]][b_support[l]])?;
+        if y.iter().any(|p| *p < -EPS) {
+            return None;
+        }
+        let mut b_mixed = vec![0.0; columns];
+        b_support
+            .iter()
+            .zip(y.iter())
+            .for_each(|(&j, p)| b_mixed[j] = p.max(0.0));
+        if (0..rows).any(|i| (0..columns).map(|j| a[i][j] * b_mixed[j]).sum::<f64>() > u + EPS) {
+            return None;
+        }
+
+        // And vice versa
+        let (x, v) = indifference(b_support.len(), |k, l| b[a_support[l]][b_support[k]])?;
+        if x.iter().any(|p| *p < -EPS) {
+            return None;
+        }
+        let mut a_mixed = vec![0.0; rows];
+        a_support
+            .iter()
+            .zip(x.iter())
+            .for_each(|(&i, p)| a_mixed[i] = p.max(0.0));
+        if (0..columns).any(|j| (0..rows).map(|i| b[i][j] * a_mixed[i]).sum::<f64>() > v + EPS) {
+            return None;
+        }
+
+        Some(Equilibrium {
+            a: a_mixed,
+            b: b_mixed,
+            payoff: (u, v),
+        })
+    }
+}
+
+// Solves sum(m(k, l) * p(l)) = w for all k and sum(p(l)) = 1, returns p and w
+pub(super) fn indifference(
+    size: usize,
+    m: impl Fn(usize, usize) -> f64,
+) -> Option<(Vec<f64>, f64)> {
+    // Unknowns are p(0..size) and w
+    let mut system = (0..size)
+        .map(|k| {
+            let mut row = (0..size).map(|l| m(k, l)).collect::<Vec<f64>>();
+            row.push(-1.0);
+            row.push(0.0);
+            row
+        })
+        .collect::<Vec<Vec<f64>>>();
+    let mut normalization = vec![1.0; size];
+    normalization.push(0.0);
+    normalization.push(1.0);
+    system.push(normalization);
+
+    let mut solution = gauss(system)?;
+    let w = solution.pop()?;
+    Some((solution, w))
+}
+
+// Gaussian elimination with partial pivoting over the augmented matrix
+fn gauss(mut system: Vec<Vec<f64>>) -> Option<Vec<f64>> {
+    let size = system.len();
+
+    for column in 0..size {
+        let pivot = (column..size).max_by(|&x, &y| {
+            system[x][column]
+                .abs()
+                .partial_cmp(&system[y][column].abs())
+                .unwrap()
+        })?;
+        if system[pivot][column].abs() < EPS {
+            return None;
+        }
+        system.swap(column, pivot);
+
+        for row in 0..size {
+            if row != column {
+                let factor = system[row][column] / system[column][column];
+                if factor != 0.0 {
+                    let pivot_row = system[column].clone();
+                    system[row]
+                        .iter_mut()
+                        .zip(pivot_row.iter())
+                        .skip(column)
+                        .for_each(|(val, p)| *val -= factor * p);
+                }
+            }
+        }
+    }
+
+    Some((0..size).map(|i| system[i][size] / system[i][i]).collect())
+}
+
+// All subsets of 0..n with the given size in lexicographic order
+pub(super) fn subsets(n: usize, size: usize) -> Vec<Vec<usize>> {
+    let mut result = Vec::new();
+    let mut current = (0..size).collect::<Vec<usize>>();
+
+    loop {
+        result.push(current.clone());
+
+        // Find the rightmost element which can be increased
+        let position = match (0..size).rev().find(|&k| current[k] < n - size + k) {
+            Some(position) => position,
+            None => return result,
+        };
+        current[position] += 1;
+        (position + 1..size).for_each(|k| current[k] = current[k - 1] + 1);
+    }
+}
+
+pub(super) fn same(x: &[f64], y: &[f64]) -> bool {
+    x.iter().zip(y.iter()).all(|(p, q)| (p - q).abs() < 1e-6)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::{super::integers, *};
+
+    #[test]
+    fn finds_battle_of_the_sexes_equilibria() {
+        let bimatrix = Bimatrix::solve(
+            &integers(&[&[2, 0], &[0, 1]]),
+            &integers(&[&[1, 0], &[0, 2]]),
+        );
+        assert_eq!(bimatrix.equilibria.len(), 3);
+        let pure = bimatrix
+            .equilibria
+            .iter()
+            .filter_map(Equilibrium::pure)
+            .collect::<Vec<(usize, usize)>>();
+        assert_eq!(pure, [(0, 0), (1, 1)]);
+        let mixed = bimatrix
+            .equilibria
+            .iter()
+            .find(|equilibrium| equilibrium.pure().is_none())
+            .unwrap();
+        assert!(same(&mixed.a, &[2.0 / 3.0, 1.0 / 3.0]));
+        assert!(same(&mixed.b, &[1.0 / 3.0, 2.0 / 3.0]));
+        assert!(same(
+            &[mixed.payoff.0, mixed.payoff.1],
+            &[2.0 / 3.0, 2.0 / 3.0]
+        ));
+        assert_eq!(bimatrix.pareto, [(0, 0), (1, 1)]);
+    }
+
+    #[test]
+    fn finds_prisoners_dilemma_equilibrium() {
+        let bimatrix = Bimatrix::solve(
+            &integers(&[&[-1, -3], &[0, -2]]),
+            &integers(&[&[-1, 0], &[-3, -2]]),
+        );
+        assert_eq!(bimatrix.equilibria.len(), 1);
+        assert_eq!(bimatrix.equilibria[0].pure(), Some((1, 1)));
+        assert!(!bimatrix.pareto.contains(&(1, 1)));
+    }
+}
@@ -0,0 +1,142 @@
+use super::StoreType;
+
+// Single row of the Brown-Robinson table
+#[derive(Clone, Debug, Default)]
+pub struct Iteration {
+    pub a: usize,           // Strategy chosen by A
+    pub b: usize,           // Strategy chosen by B
+    pub a_gains: Vec<f64>,  // Accumulated gains of each Ai against chosen Bj
+    pub b_losses: Vec<f64>, // Accumulated losses of each Bj against chosen Ai
+    pub upper: f64,         // max(a_gains) / k
+    pub lower: f64,         // min(b_losses) / k
+}
+
+// Approximate solution of the game by fictitious play
+#[derive(Clone, Debug, Default)]
+pub struct BrownRobinson {
+    pub iterations: Vec<Iteration>,
+    pub a_frequencies: Vec<f64>,
+    pub b_frequencies: Vec<f64>,
+    pub upper: f64, // Best (lowest) upper bound of V
+    pub lower: f64, // Best (highest) lower bound of V
+}
+
+impl BrownRobinson {
+    // Players start with max-min and min-max strategies,
+    // then each one chooses the best response to the accumulated choices of the opponent
+    pub fn run(matrix: &[Vec<StoreType>], count: usize) -> Self {
+        let rows = matrix.len();
+        let columns = matrix.first().map_or(0, Vec::len);
+        if rows == 0 || columns == 0 || count == 0 {
+            return Self::default();
+        }
+
+        let mut a = (0..rows)
+            .max_by_key(|&i| (matrix[i].iter().min(), std::cmp::Reverse(i)))
+            .unwrap();
+        let mut b = (0..columns)
+            .min_by_key(|&j| (matrix.iter().map(|row| row[j]).max(), j))
+            .unwrap();
+
+        let mut a_gains = vec![0.0; rows];
+        let mut b_losses = vec![0.0; columns];
+        let mut a_counts = vec![0usize; rows];
+        let mut b_counts = vec![0usize; columns];
+
+        let mut iterations = Vec::with_capacity(count);
+        let (mut upper, mut lower) = (f64::INFINITY, f64::NEG_INFINITY);
+
+        for k in 1..=count {
+            a_counts[a] += 1;
+            b_counts[b] += 1;
+            a_gains
+                .iter_mut()
+                .enumerate()
+                .for_each(|(i, gain)| *gain += matrix[i][b].to_f64());
+            b_losses
+                .iter_mut()
+                .enumerate()
+                .for_each(|(j, loss)| *loss += matrix[a][j].to_f64());
+
+            let (next_a, max_gain) = Self::best(&a_gains, |x, y| x > y);
+            let (next_b, min_loss) = Self::best(&b_losses, |x, y| x < y);
+
+            let iteration = Iteration {
+                a,
+                b,
+                a_gains: a_gains.clone(),
+                b_losses: b_losses.clone(),
+                upper: max_gain / k as f64,
+                lower: min_loss / k as f64,
+            };
+            upper = upper.min(iteration.upper);
+            lower = lower.max(iteration.lower);
+            iterations.push(iteration);
+
+            a = next_a;
+            b = next_b;
+        }
+
+        Self {
+            iterations,
+            a_frequencies: a_counts.iter().map(|c| *c as f64 / count as f64).collect(),
+            b_frequencies: b_counts.iter().map(|c| *c as f64 / count as f64).collect(),
+            upper,
+            lower,
+        }
+    }
+
+    // Index and value of the best element, the first one wins ties
+    fn best(values: &[f64], better: impl Fn(f64, f64) -> bool) -> (usize, f64) {
+        values
+            .iter()
+            .enumerate()
+            .fold((0, values[0]), |best, (i, val)| {
+                if better(*val, best.1) {
+                    (i, *val)
+                } else {
+                    best
+                }
+            })
+    }
+
+    // Approximate game value
+    pub fn value(&self) -> f64 {
+        (self.upper + self.lower) / 2.0
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::{super::integers, *};
+
+    #[test]
+    fn bounds_narrow_around_the_value() {
+        let brown_robinson = BrownRobinson::run(&integers(&[&[1, -1], &[-1, 1]]), 1000);
+        assert!(brown_robinson.lower <= 0.0 && 0.0 <= brown_robinson.upper);
+
+        // Bounds of a single iteration bracket the value as well and get closer over time
+        let widths = [9, 99, 999].map(|k| {
+            let iteration = &brown_robinson.iterations[k];
+            assert!(iteration.lower <= 0.0 && 0.0 <= iteration.upper);
+            iteration.upper - iteration.lower
+        });
+        assert!(widths[0] > widths[1] && widths[1] > widths[2]);
+        assert!(widths[2] < 0.1);
+
+        assert!((brown_robinson.a_frequencies[0] - 0.5).abs() < 0.05);
+        assert!((brown_robinson.b_frequencies[0] - 0.5).abs() < 0.05);
+    }
+
+    #[test]
+    fn best_bounds_bracket_the_value() {
+        let matrix = integers(&[&[2, 6, 4], &[0, 0, 5], &[4, -4, 4]]);
+        let widths = [10, 1000].map(|count| {
+            let brown_robinson = BrownRobinson::run(&matrix, count);
+            assert!(brown_robinson.lower <= 8.0 / 3.0 && 8.0 / 3.0 <= brown_robinson.upper);
+            brown_robinson.upper - brown_robinson.lower
+        });
+        assert!(widths[0] > widths[1]);
+        assert!(widths[1] < 0.1);
+    }
+}
@@ -0,0 +1,210 @@
+use std::fmt;
+
+use super::{
+    mixed::{ExactSolution, MixedSolution},
+    number::Field,
+    StoreType,
+};
+
+#[derive(Clone, Copy, Debug, PartialEq)]
+pub enum Player {
+    A,
+    B,
+}
+
+impl Player {
+    pub fn as_str(&self) -> &str {
+        match self {
+            Player::A => "A",
+            Player::B => "B",
+        }
+    }
+}
+
+// Single elimination step, indices are relative to the original matrix
+#[derive(Clone, Debug)]
+pub struct DominanceStep {
+    pub player: Player,
+    pub removed: usize,
+    pub by: usize,
+    pub strict: bool,
+}
+
+impl fmt::Display for DominanceStep {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        let player = self.player.as_str();
+        write!(
+            f,
+            "{player}{} {} dominated by {player}{}",
+            self.removed,
+            if self.strict { "strictly" } else { "weakly" },
+            self.by
+        )
+    }
+}
+
+// Matrix left after the iterated elimination of dominated strategies
+#[derive(Clone, Debug, Default)]
+pub struct Reduction {
+    pub matrix: Vec<Vec<StoreType>>,
+    pub rows: Vec<usize>,    // Original indices of the remaining Ai
+    pub columns: Vec<usize>, // Original indices of the remaining Bj
+    pub steps: Vec<DominanceStep>,
+}
+
+impl Reduction {
+    // Repeatedly removes dominated rows (A maximizes) and columns (B minimizes),
+    // strict dominance is always looked for before the weak one
+    pub fn reduce(matrix: &[Vec<StoreType>]) -> Self {
+        let mut rows = (0..matrix.len()).collect::<Vec<usize>>();
+        let mut columns = (0..matrix.first().map_or(0, Vec::len)).collect::<Vec<usize>>();
+        let mut steps = Vec::new();
+
+        loop {
+            let step = [true, false].iter().find_map(|&strict| {
+                Self::dominated_row(matrix, &rows, &columns, strict)
+                    .map(|(removed, by)| (Player::A, removed, by, strict))
+                    .or_else(|| {
+                        Self::dominated_column(matrix, &rows, &columns, strict)
+                            .map(|(removed, by)| (Player::B, removed, by, strict))
+                    })
+            });
+
+            match step {
+                Some((player, removed, by, strict)) => {
+                    match player {
+                        Player::A => rows.retain(|&i| i != removed),
+                        Player::B => columns.retain(|&j| j != removed),
+                    }
+                    steps.push(DominanceStep {
+                        player,
+                        removed,
+                        by,
+                        strict,
+                    });
+                }
+                None => break,
+            }
+        }
+
+        Self {
+            matrix: rows
+                .iter()
+                .map(|&i| columns.iter().map(|&j| matrix[i][j]).collect())
+                .collect(),
+            rows,
+            columns,
+            steps,
+        }
+    }
+
+    // Finds row k and row r such that r dominates k
+    fn dominated_row(
+        matrix: &[Vec<StoreType>],
+        rows: &[usize],
+        columns: &[usize],
+        strict: bool,
+    ) -> Option<(usize, usize)> {
+        Self::dominated(rows, |k, r| {
+            Self::dominates(
+                columns.iter().map(|&j| (matrix[r][j], matrix[k][j])),
+                strict,
+            )
+        })
+    }
+
+    // Finds column k and column c such that c dominates k (B prefers lower payoffs)
+    fn dominated_column(
+        matrix: &[Vec<StoreType>],
+        rows: &[usize],
+        columns: &[usize],
+        strict: bool,
+    ) -> Option<(usize, usize)> {
+        Self::dominated(columns, |k, c| {
+            Self::dominates(rows.iter().map(|&i| (matrix[i][k], matrix[i][c])), strict)
+        })
+    }
+
+    fn dominated(
+        indices: &[usize],
+        dominates: impl Fn(usize, usize) -> bool,
+    ) -> Option<(usize, usize)> {
+        indices.iter().rev().find_map(|&k| {
+            indices
+                .iter()
+                .find(|&&other| other != k && dominates(k, other))
+                .map(|&other| (k, other))
+        })
+    }
+
+    // Whether the first value of each pair is greater than the second one
+    fn dominates(mut pairs: impl Iterator<Item = (StoreType, StoreType)>, strict: bool) -> bool {
+        if strict {
+            pairs.all(|(first, second)| first > second)
+        } else {
+            pairs.all(|(first, second)| first >= second)
+        }
+    }
+
+    // Whether no strategy of the matrix is dominated, even weakly
+    pub fn is_reduced(matrix: &[Vec<StoreType>]) -> bool {
+        let rows = (0..matrix.len()).collect::<Vec<usize>>();
+        let columns = (0..matrix.first().map_or(0, Vec::len)).collect::<Vec<usize>>();
+        Self::dominated_row(matrix, &rows, &columns, false).is_none()
+            && Self::dominated_column(matrix, &rows, &columns, false).is_none()
+    }
+
+    // Maps the solution of the reduced game back to the original strategies
+    pub fn expand(&self, solution: MixedSolution, rows: usize, columns: usize) -> MixedSolution {
+        MixedSolution {
+            a: spread(&self.rows, &solution.a, rows),
+            b: spread(&self.columns, &solution.b, columns),
+            value: solution.value,
+            exact: solution.exact.map(|exact| ExactSolution {
+                a: spread(&self.rows, &exact.a, rows),
+                b: spread(&self.columns, &exact.b, columns),
+                value: exact.value,
+            }),
+        }
+    }
+}
+
+// Probabilities of the remaining strategies placed among all of them, removed ones get zero
+fn spread<T: Field>(indices: &[usize], probabilities: &[T], len: usize) -> Vec<T> {
+    let mut spread = vec![T::zero(); len];
+    indices
+        .iter()
+        .zip(probabilities.iter())
+        .for_each(|(&k, p)| spread[k] = *p);
+    spread
+}
+
+#[cfg(test)]
+mod tests {
+    use super::{
+        super::{integers, Rational},
+        *,
+    };
+
+    #[test]
+    fn reduces_and_expands() {
+        let reduction = Reduction::reduce(&integers(&[&[3, 1, 4], &[2, 0, 3], &[1, 5, 2]]));
+        assert_eq!(reduction.rows, [0, 2]);
+        assert_eq!(reduction.columns, [0, 1]);
+        assert_eq!(reduction.matrix, integers(&[&[3, 1], &[1, 5]]));
+        let steps = reduction
+            .steps
+            .iter()
+            .map(|step| (step.player, step.removed, step.by, step.strict))
+            .collect::<Vec<_>>();
+        assert_eq!(steps, [(Player::A, 1, 0, true), (Player::B, 2, 0, true)]);
+        assert!(Reduction::is_reduced(&reduction.matrix));
+
+        let solution = MixedSolution::solve(&reduction.matrix).unwrap();
+        let exact = reduction.expand(solution, 3, 3).exact.unwrap();
+        let zero = Rational::integer(0);
+        assert_eq!(exact.value, Rational::new(7, 3));
+        assert_eq!(exact.a, [Rational::new(2, 3), zero, Rational::new(1, 3)]);
+        assert_eq!(exact.b, [Rational::new(2, 3), Rational::new(1, 3), zero]);
+    }
+}
@@ -0,0 +1,154 @@
+use super::{dominance::Player, Rational, StoreType};
+
+// Tolerance of the probabilities sum and of equally good responses
+const EPS: f64 = 1e-6;
+
+// Mixed strategy of one player checked against every pure strategy of the opponent
+#[derive(Clone, Debug)]
+pub struct Evaluation {
+    pub payoffs: Vec<f64>, // Expected payoff of A against each opponent strategy
+    pub guaranteed: f64,   // Worst of the payoffs for the player
+    pub best_responses: Vec<usize>, // Opponent strategies reaching the guaranteed payoff
+}
+
+impl Evaluation {
+    pub fn evaluate(
+        matrix: &[Vec<StoreType>],
+        player: Player,
+        strategy: &[f64],
+    ) -> Result<Self, String> {
+        let (length, opponent_length) = match player {
+            Player::A => (matrix.len(), matrix.first().map_or(0, Vec::len)),
+            Player::B => (matrix.first().map_or(0, Vec::len), matrix.len()),
+        };
+        if strategy.len() != length {
+            return Err(format!(
+                "Expected {length} probabilities, got {}",
+                strategy.len()
+            ));
+        }
+        if let Some(p) = strategy.iter().find(|p| **p < 0.0) {
+            return Err(format!("Negative probability {p}"));
+        }
+        let total = strategy.iter().sum::<f64>();
+        if (total - 1.0).abs() > EPS {
+            return Err(format!("Probabilities sum to {total}, not 1"));
+        }
+
+        let payoffs = (0..opponent_length)
+            .map(|k| {
+                strategy
+                    .iter()
+                    .enumerate()
+                    .map(|(l, p)| {
+                        let val = match player {
+                            Player::A => matrix[l][k],
+                            Player::B => matrix[k][l],
+                        };
+                        p * val.to_f64()
+                    })
+                    .sum()
+            })
+            .collect::<Vec<f64>>();
+
+        // A can't get less than the lowest payoff, B can't pay more than the highest one
+        let guaranteed = match player {
+            Player::A => payoffs.iter().copied().fold(f64::INFINITY, f64::min),
+            Player::B => payoffs.iter().copied().fold(f64::NEG_INFINITY, f64::max),
+        };
+        let best_responses = payoffs
+            .iter()
+            .enumerate()
+            .filter(|(_, payoff)| (*payoff - guaranteed).abs() < EPS)
+            .map(|(k, _)| k)
+            .collect();
+
+        Ok(Self {
+            payoffs,
+            guaranteed,
+            best_responses,
+        })
+    }
+
+    // Payoff lost by the player compared to the optimal strategy with the game value
+    pub fn exploitability(&self, player: Player, value: f64) -> f64 {
+        match player {
+            Player::A => value - self.guaranteed,
+            Player::B => self.guaranteed - value,
+        }
+    }
+
+    // Parses probabilities separated by commas, semicolons or spaces,
+    // each one is a fraction "a/b" or a decimal
+    pub fn parse(text: &str) -> Result<Vec<f64>, String> {
+        text.split(|c: char| c == ',' || c == ';' || c.is_whitespace())
+            .filter(|token| !token.is_empty())
+            .map(|token| {
+                token
+                    .parse::<Rational>()
+                    .map(Rational::to_f64)
+                    .or_else(|_| token.parse::<f64>().map_err(|_| ()))
+                    .ok()
+                    .filter(|p| p.is_finite())
+                    .ok_or_else(|| format!("Invalid probability \"{token}\""))
+            })
+            .collect()
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::{super::integers, *};
+
+    fn evaluate(text: &str) -> Result<Evaluation, String> {
+        let matrix = integers(&[&[1, -1], &[-1, 1]]);
+        Evaluation::parse(text)
+            .and_then(|strategy| Evaluation::evaluate(&matrix, Player::A, &strategy))
+    }
+
+    #[test]
+    fn parses_fractions_and_decimals() {
+        assert_eq!(
+            Evaluation::parse("1/4, 0.5; 1/4"),
+            Ok(vec![0.25, 0.5, 0.25])
+        );
+        assert_eq!(
+            Evaluation::parse("1/2 x"),
+            Err("Invalid probability \"x\"".to_string())
+        );
+        assert!(Evaluation::parse("inf 0").is_err());
+    }
+
+    #[test]
+    fn rejects_invalid_strategies() {
+        assert_eq!(
+            evaluate("1/3 1/3 1/3").unwrap_err(),
+            "Expected 2 probabilities, got 3"
+        );
+        assert_eq!(evaluate("").unwrap_err(), "Expected 2 probabilities, got 0");
+        assert_eq!(
+            evaluate("-1/2 3/2").unwrap_err(),
+            "Negative probability -0.5"
+        );
+        assert_eq!(
+            evaluate("0 0").unwrap_err(),
+            "Probabilities sum to 0, not 1"
+        );
+        assert_eq!(
+            evaluate("0.5 0.6").unwrap_err(),
+            "Probabilities sum to 1.1, not 1"
+        );
+    }
+
+    #[test]
+    fn evaluates_strategies() {
+        let evaluation = evaluate("1/2 1/2").unwrap();
+        assert_eq!(evaluation.payoffs, [0.0, 0.0]);
+        assert_eq!(evaluation.best_responses, [0, 1]);
+
+        let evaluation = evaluate("1 0").unwrap();
+        assert_eq!(evaluation.guaranteed, -1.0);
+        assert_eq!(evaluation.best_responses, [1]);
+        assert_eq!(evaluation.exploitability(Player::A, 0.0), 1.0);
+    }
+}
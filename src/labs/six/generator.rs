@@ -0,0 +1,256 @@
+use rand::Rng;
+
+use super::{dominance::Reduction, Game, StoreType, VALUE_LIMIT};
+
+// Attempts to draw the part of the matrix without dominated strategies
+const CORE_ATTEMPTS: usize = 100;
+
+// Property the random game is guaranteed to have
+#[derive(Clone, Copy, Debug, Default, PartialEq)]
+pub enum Generator {
+    #[default]
+    Uniform,
+    OneSaddle,
+    MultipleSaddles,
+    NoSaddle,
+    Dominated,
+}
+
+impl Generator {
+    pub const ALL: [Generator; 5] = [
+        Generator::Uniform,
+        Generator::OneSaddle,
+        Generator::MultipleSaddles,
+        Generator::NoSaddle,
+        Generator::Dominated,
+    ];
+
+    pub fn as_str(&self) -> &str {
+        match self {
+            Generator::Uniform => "Uniform",
+            Generator::OneSaddle => "One Saddle Point",
+            Generator::MultipleSaddles => "Multiple Saddle Points",
+            Generator::NoSaddle => "No Saddle Point",
+            Generator::Dominated => "Dominated Strategies",
+        }
+    }
+}
+
+// Index in 0..len, drawn the same way on 32-bit and 64-bit targets
+fn index(rng: &mut impl Rng, len: usize) -> usize {
+    rng.gen_range(0..len as u64) as usize
+}
+
+// Sorted distinct random positions in 0..=len including both ends, count >= 2
+fn positions(rng: &mut impl Rng, len: usize, count: usize) -> Vec<i64> {
+    let mut positions = sample(rng, len - 1, count - 2)
+        .into_iter()
+        .map(|k| k as i64 + 1)
+        .chain([0, len as i64])
+        .collect::<Vec<i64>>();
+    positions.sort_unstable();
+    positions
+}
+
+// Distinct random indices in 0..len (partial Fisher-Yates shuffle)
+fn sample(rng: &mut impl Rng, len: usize, count: usize) -> Vec<usize> {
+    let mut indices = (0..len).collect::<Vec<usize>>();
+    (0..count).for_each(|k| {
+        let other = k + index(rng, len - k);
+        indices.swap(k, other);
+    });
+    indices.truncate(count);
+    indices
+}
+
+impl Game {
+    // Overwrites values of the uniformly random matrix, so the game gets the property
+    // of the generator. Values stay in the value range, which is widened to at least 2
+    // and as far as the core of the game with dominated strategies needs
+    pub fn generate(&mut self, rng: &mut impl Rng) {
+        let (lo, hi) = (
+            self.value_range.0,
+            self.value_range.1.max(self.value_range.0 + 2),
+        );
+
+        match self.generator {
+            Generator::Uniform => {}
+            Generator::OneSaddle => {
+                let rows = sample(rng, self.rows, 1);
+                let columns = sample(rng, self.columns, 1);
+                self.generate_saddles(rng, (lo, hi), &rows, &columns);
+            }
+            Generator::MultipleSaddles => {
+                let (rows, columns) = [(1, 2), (2, 1), (2, 2)][index(rng, 3)];
+                let rows = sample(rng, self.rows, rows);
+                let columns = sample(rng, self.columns, columns);
+                self.generate_saddles(rng, (lo, hi), &rows, &columns);
+            }
+            Generator::NoSaddle => self.generate_no_saddle(rng, (lo, hi)),
+            Generator::Dominated => self.generate_dominated(rng, (lo, hi)),
+        }
+    }
+
+    // Saddle points are exactly the cells of the given rows and columns: they hold the value v,
+    // other values of the rows are greater and other values of the columns are lower
+    fn generate_saddles(
+        &mut self,
+        rng: &mut impl Rng,
+        (lo, hi): (i64, i64),
+        rows: &[usize],
+        columns: &[usize],
+    ) {
+        let v = rng.gen_range(lo + 1..=hi - 1);
+        let kind = self.kind;
+
+        (0..self.rows).for_each(|i| {
+            (0..self.columns).for_each(|j| {
+                let val = &mut self.matrix[i][j];
+                match (rows.contains(&i), columns.contains(&j)) {
+                    (true, true) => *val = StoreType::Integer(v).convert(kind),
+                    (true, false) => *val = StoreType::random(rng, kind, (v + 1, hi)),
+                    (false, true) => *val = StoreType::random(rng, kind, (lo, v - 1)),
+                    (false, false) => {}
+                }
+            })
+        });
+    }
+
+    // Every row gets a value below v and every column gets a value above v,
+    // so max(min(Ai)) < v < min(max(Bj))
+    fn generate_no_saddle(&mut self, rng: &mut impl Rng, (lo, hi): (i64, i64)) {
+        let v = rng.gen_range(lo + 1..=hi - 1);
+        let kind = self.kind;
+        let rows = sample(rng, self.rows, self.rows);
+        let columns = sample(rng, self.columns, self.columns);
+
+        // Low cell of the row k is in the column k % N, high cell of the column l is
+        // in the row (l + 1) % M, these never coincide when N >= 2
+        (0..self.columns).for_each(|l| {
+            self.matrix[rows[(l + 1) % self.rows]][columns[l]] =
+                StoreType::random(rng, kind, (v + 1, hi));
+        });
+        (0..self.rows).for_each(|k| {
+            self.matrix[rows[k]][columns[k % self.columns]] =
+                StoreType::random(rng, kind, (lo, v - 1));
+        });
+    }
+
+    // Dominated strategies are copies of the other ones shifted by a constant, A loses
+    // and B pays more with them. The rest of the matrix is drawn again until it has no
+    // dominated strategies or built without them, so the dominance reduction removes exactly
+    // the generated ones unless a single strategy of a player is left
+    fn generate_dominated(&mut self, rng: &mut impl Rng, (lo, hi): (i64, i64)) {
+        let count = self.dominated.min(self.rows + self.columns - 2);
+        let rows_count = (count - count / 2).min(self.rows - 1);
+        let columns_count = (count - rows_count).min(self.columns - 1);
+
+        let rows = sample(rng, self.rows, self.rows);
+        let columns = sample(rng, self.columns, self.columns);
+        let (dominated_rows, core_rows) = rows.split_at(rows_count);
+        let (dominated_columns, core_columns) = columns.split_at(columns_count);
+
+        // Core values leave room for the shifts and a distinct value for every strategy
+        let core_len = core_rows.len().max(core_columns.len()) as i64;
+        let hi = hi.max(lo + 2 * core_len + 2).min(*VALUE_LIMIT.end());
+        let lo = lo.min(hi - 2 * core_len - 2);
+        let max_shift = ((hi - lo) / 4).max(1);
+        let core_range = (lo + max_shift, hi - max_shift);
+        let reduced = (0..CORE_ATTEMPTS).any(|_| {
+            core_rows.iter().for_each(|&i| {
+                core_columns.iter().for_each(|&j| {
+                    self.matrix[i][j] = StoreType::random(rng, self.kind, core_range);
+                })
+            });
+            let core = core_rows
+                .iter()
+                .map(|&i| core_columns.iter().map(|&j| self.matrix[i][j]).collect())
+                .collect::<Vec<Vec<StoreType>>>();
+            Reduction::is_reduced(&core)
+        });
+
+        // Otherwise values are distances between the positions of the strategies spanning
+        // the same segment: of two rows, the one closer to the start is closer to the first
+        // column and farther from the last one, so neither dominates, the same for columns
+        if !reduced && core_rows.len() > 1 && core_columns.len() > 1 {
+            let len = (core_range.1 - core_range.0) as usize;
+            let x = positions(rng, len, core_rows.len());
+            let y = positions(rng, len, core_columns.len());
+            let near = rng.gen_bool(0.5);
+            core_rows.iter().zip(x.iter()).for_each(|(&i, x)| {
+                core_columns.iter().zip(y.iter()).for_each(|(&j, y)| {
+                    let val = if near {
+                        core_range.1 - (x - y).abs()
+                    } else {
+                        core_range.0 + (x - y).abs()
+                    };
+                    self.matrix[i][j] = StoreType::Integer(val).convert(self.kind);
+                })
+            });
+        }
+
+        // Shifted values stay within the value range, so they never overflow
+        dominated_columns.iter().for_each(|&l| {
+            let by = core_columns[index(rng, core_columns.len())];
+            let shift = rng.gen_range(1..=max_shift);
+            core_rows.iter().for_each(|&i| {
+                if let Some(val) = self.matrix[i][by].shift(shift) {
+                    self.matrix[i][l] = val;
+                }
+            });
+        });
+        dominated_rows.iter().for_each(|&k| {
+            let by = core_rows[index(rng, core_rows.len())];
+            let shift = rng.gen_range(1..=max_shift);
+            (0..self.columns).for_each(|j| {
+                if let Some(val) = self.matrix[by][j].shift(-shift) {
+                    self.matrix[k][j] = val;
+                }
+            });
+        });
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    // Games of the generator over several seeds
+    fn games(generator: Generator, length: (usize, usize)) -> impl Iterator<Item = Game> {
+        (0..20).map(move |seed| {
+            let mut game = Game {
+                generator,
+                dominated: 3,
+                value_range: (0, 2),
+                seed,
+                ..Default::default()
+            };
+            game.resize(length);
+            game
+        })
+    }
+
+    #[test]
+    fn generates_saddle_points() {
+        games(Generator::OneSaddle, (4, 5)).for_each(|game| {
+            assert_eq!(game.saddle_points.len(), 1);
+        });
+        games(Generator::MultipleSaddles, (4, 5)).for_each(|game| {
+            assert!([2, 4].contains(&game.saddle_points.len()));
+        });
+        games(Generator::NoSaddle, (4, 5)).for_each(|game| {
+            assert!(game.saddle_points.is_empty());
+        });
+    }
+
+    #[test]
+    fn generates_dominated_strategies() {
+        [(4, 5), (4, 3), (8, 3), (30, 30)]
+            .into_iter()
+            .for_each(|length| {
+                games(Generator::Dominated, length).for_each(|mut game| {
+                    assert_eq!(game.reduction().steps.len(), 3);
+                });
+            });
+    }
+}
@@ -0,0 +1,160 @@
+use super::StoreType;
+
+// Tolerance used when comparing line values
+const EPS: f64 = 1e-9;
+
+// Solution of 2xN or Mx2 game with the graphical method
+#[derive(Clone, Debug, Default)]
+pub struct GraphicalSolution {
+    // Whether lines belong to A strategies (Mx2 game), so the upper envelope is minimized
+    pub upper: bool,
+    // Payoff line of each opponent strategy as values at p = 0 and p = 1
+    pub lines: Vec<(f64, f64)>,
+    // Break points of the envelope
+    pub envelope: Vec<(f64, f64)>,
+    // Optimal probability of the first strategy and the game value
+    pub optimum: (f64, f64),
+    // Opponent strategies passing through the optimum
+    pub active: Vec<usize>,
+}
+
+impl GraphicalSolution {
+    // For 2xN game p is the probability of A0 and lines are Bj,
+    // for Mx2 game p is the probability of B0 and lines are Ai.
+    pub fn solve(matrix: &[Vec<StoreType>]) -> Option<Self> {
+        let rows = matrix.len();
+        let columns = matrix.first()?.len();
+
+        let (upper, lines) = if rows == 2 {
+            (
+                false,
+                (0..columns)
+                    .map(|j| (matrix[1][j].to_f64(), matrix[0][j].to_f64()))
+                    .collect::<Vec<(f64, f64)>>(),
+            )
+        } else if columns == 2 {
+            (
+                true,
+                matrix
+                    .iter()
+                    .map(|row| (row[1].to_f64(), row[0].to_f64()))
+                    .collect::<Vec<(f64, f64)>>(),
+            )
+        } else {
+            return None;
+        };
+
+        #[inline]
+        fn at(line: &(f64, f64), p: f64) -> f64 {
+            line.0 + (line.1 - line.0) * p
+        }
+
+        let envelope_at = |p: f64| {
+            let values = lines.iter().map(|line| at(line, p));
+            if upper {
+                values.fold(f64::NEG_INFINITY, f64::max)
+            } else {
+                values.fold(f64::INFINITY, f64::min)
+            }
+        };
+
+        // Candidate break points are the ends of the segment and all pairwise intersections
+        let mut points = vec![0.0, 1.0];
+        lines.iter().enumerate().for_each(|(i, first)| {
+            lines.iter().skip(i + 1).for_each(|second| {
+                let slope = (first.1 - first.0) - (second.1 - second.0);
+                if slope.abs() > EPS {
+                    let p = (second.0 - first.0) / slope;
+                    if p > 0.0 && p < 1.0 {
+                        points.push(p);
+                    }
+                }
+            })
+        });
+        points.sort_by(|a, b| a.partial_cmp(b).unwrap());
+        points.dedup_by(|a, b| (*a - *b).abs() < EPS);
+
+        let envelope = points
+            .iter()
+            .map(|&p| (p, envelope_at(p)))
+            .collect::<Vec<(f64, f64)>>();
+
+        let optimum = *envelope
+            .iter()
+            .reduce(|best, point| {
+                let better = if upper {
+                    point.1 < best.1 - EPS
+                } else {
+                    point.1 > best.1 + EPS
+                };
+                if better {
+                    point
+                } else {
+                    best
+                }
+            })
+            .unwrap();
+
+        let active = lines
+            .iter()
+            .enumerate()
+            .filter(|(_, line)| (at(line, optimum.0) - optimum.1).abs() < EPS)
+            .map(|(i, _)| i)
+            .collect();
+
+        Some(Self {
+            upper,
+            lines,
+            envelope,
+            optimum,
+            active,
+        })
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::{
+        super::{integers, mixed::MixedSolution},
+        *,
+    };
+
+    fn close(x: f64, y: f64) -> bool {
+        (x - y).abs() < 1e-9
+    }
+
+    #[test]
+    fn solves_two_rows() {
+        let matrix = integers(&[&[2, 3, 11], &[7, 5, 2]]);
+        let solution = GraphicalSolution::solve(&matrix).unwrap();
+        assert!(!solution.upper);
+        assert!(close(solution.optimum.0, 3.0 / 11.0));
+        assert!(close(solution.optimum.1, 49.0 / 11.0));
+        assert_eq!(solution.active, [1, 2]);
+
+        let mixed = MixedSolution::solve(&matrix).unwrap();
+        assert!(close(mixed.a[0], solution.optimum.0));
+        assert!(close(mixed.value, solution.optimum.1));
+    }
+
+    #[test]
+    fn solves_two_columns() {
+        let matrix = integers(&[&[2, 7], &[3, 5], &[11, 2]]);
+        let solution = GraphicalSolution::solve(&matrix).unwrap();
+        assert!(solution.upper);
+        assert!(close(solution.optimum.0, 5.0 / 14.0));
+        assert!(close(solution.optimum.1, 73.0 / 14.0));
+        assert_eq!(solution.active, [0, 2]);
+
+        let mixed = MixedSolution::solve(&matrix).unwrap();
+        assert!(close(mixed.b[0], solution.optimum.0));
+        assert!(close(mixed.value, solution.optimum.1));
+    }
+
+    #[test]
+    fn skips_larger_games() {
+        assert!(
+            GraphicalSolution::solve(&integers(&[&[1, 2, 3], &[4, 5, 6], &[7, 8, 9]])).is_none()
+        );
+    }
+}
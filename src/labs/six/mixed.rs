@@ -0,0 +1,317 @@
+use serde::Serialize;
+
+use super::{number::Field, Rational, StoreType};
+
+// Degenerate pivots allowed before switching to Bland's rule
+const DEGENERATE_LIMIT: usize = 64;
+
+// Largest game solved in fractions, their pivots are much slower than the float ones
+const EXACT_CELLS: usize = 400;
+
+// Solution of the game in mixed strategies
+#[derive(Clone, Debug, Default, Serialize)]
+pub struct MixedSolution {
+    pub a: Vec<f64>, // Optimal probabilities of Ai
+    pub b: Vec<f64>, // Optimal probabilities of Bj
+    pub value: f64,  // Game value V
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub exact: Option<ExactSolution>, // The same solution in fractions
+}
+
+// Solution found without rounding, present for games of integers and fractions
+#[derive(Clone, Debug, Serialize)]
+pub struct ExactSolution {
+    pub a: Vec<Rational>,
+    pub b: Vec<Rational>,
+    pub value: Rational,
+}
+
+impl MixedSolution {
+    // Solves the game in fractions when all payoffs are exact and the game is small enough,
+    // in floats otherwise or if fractions overflow
+    pub fn solve(matrix: &[Vec<StoreType>]) -> Option<Self> {
+        let cells = matrix.len() * matrix.first().map_or(0, Vec::len);
+        let exact = if cells <= EXACT_CELLS {
+            solve_in::<Rational>(matrix)
+        } else {
+            None
+        };
+
+        match exact {
+            Some((a, b, value)) => Some(Self {
+                a: a.iter().map(|p| p.to_f64()).collect(),
+                b: b.iter().map(|q| q.to_f64()).collect(),
+                value: value.to_f64(),
+                exact: Some(ExactSolution { a, b, value }),
+            }),
+            None => {
+                let (a, b, value) = solve_in::<f64>(matrix)?;
+                Some(Self {
+                    a,
+                    b,
+                    value,
+                    exact: None,
+                })
+            }
+        }
+    }
+}
+
+// Optimal strategies of A and B and the game value, None if a payoff or a step
+// doesn't fit the field
+fn solve_in<T: Field>(matrix: &[Vec<StoreType>]) -> Option<(Vec<T>, Vec<T>, T)> {
+    let matrix = matrix
+        .iter()
+        .map(|row| row.iter().map(|&val| T::from_number(val)).collect())
+        .collect::<Option<Vec<Vec<T>>>>()?;
+    match matrix.as_slice() {
+        [first, second] if first.len() == 2 => {
+            solve_2x2([first[0], first[1], second[0], second[1]]).or_else(|| solve_lp(&matrix))
+        }
+        _ => solve_lp(&matrix),
+    }
+}
+
+// Closed form for the 2x2 game without a saddle point, None if the game has one
+fn solve_2x2<T: Field>([a11, a12, a21, a22]: [T; 4]) -> Option<(Vec<T>, Vec<T>, T)> {
+    // p = (a22 - a21) / d, q = (a22 - a12) / d, V = (a11 * a22 - a12 * a21) / d,
+    // where d = a11 + a22 - a12 - a21
+    let d = a11.checked_add(a22)?.checked_sub(a12)?.checked_sub(a21)?;
+    if !d.is_positive() && !d.is_negative() {
+        return None;
+    }
+    let p = a22.checked_sub(a21)?.checked_div(d)?;
+    let q = a22.checked_sub(a12)?.checked_div(d)?;
+    let value = a11
+        .checked_mul(a22)?
+        .checked_sub(a12.checked_mul(a21)?)?
+        .checked_div(d)?;
+
+    let one = T::one();
+    let inside = |x: T| !x.is_negative() && x.checked_sub(one).is_some_and(|y| !y.is_positive());
+    if !inside(p) || !inside(q) {
+        return None;
+    }
+    Some((
+        vec![p, one.checked_sub(p)?],
+        vec![q, one.checked_sub(q)?],
+        value,
+    ))
+}
+
+// Reduces the game to the linear program
+//   max(sum(yj)), sum(aij * yj) <= 1, yj >= 0
+// for player B; the dual of it gives the strategy of player A.
+fn solve_lp<T: Field>(matrix: &[Vec<T>]) -> Option<(Vec<T>, Vec<T>, T)> {
+    let rows = matrix.len();
+    let columns = matrix.first()?.len();
+
+    // Shift all payoffs so they become strictly positive, this keeps the value positive
+    let min = matrix
+        .iter()
+        .flatten()
+        .copied()
+        .reduce(|x, y| if y < x { y } else { x })?;
+    let shift = T::one().checked_sub(min)?;
+
+    let mut simplex = Simplex::new(rows, columns, |i, j| matrix[i][j].checked_add(shift))?;
+    simplex.run()?;
+
+    let total = simplex.objective();
+    if !total.is_positive() {
+        return None;
+    }
+
+    let a = (0..rows)
+        .map(|i| simplex.dual(i).checked_div(total))
+        .collect::<Option<Vec<T>>>()?;
+    let b = (0..columns)
+        .map(|j| simplex.primal(j).checked_div(total))
+        .collect::<Option<Vec<T>>>()?;
+    let value = T::one().checked_div(total)?.checked_sub(shift)?;
+
+    Some((a, b, value))
+}
+
+// -------------------------------------------------------------------------------------------------
+
+// Simplex tableau for max(c * y), A * y <= 1, y >= 0 where c = (1, ..., 1)
+struct Simplex<T> {
+    // Constraint rows followed by the objective row, each row is [y | slack | rhs]
+    tableau: Vec<Vec<T>>,
+    basis: Vec<usize>,
+    vars: usize,
+}
+
+impl<T: Field> Simplex<T> {
+    fn new(rows: usize, vars: usize, coeff: impl Fn(usize, usize) -> Option<T>) -> Option<Self> {
+        let width = vars + rows + 1;
+        let mut tableau = Vec::with_capacity(rows + 1);
+
+        for i in 0..rows {
+            let mut row = vec![T::zero(); width];
+            for (j, val) in row.iter_mut().enumerate().take(vars) {
+                *val = coeff(i, j)?;
+            }
+            row[vars + i] = T::one();
+            row[width - 1] = T::one();
+            tableau.push(row);
+        }
+
+        let mut objective = vec![T::zero(); width];
+        let minus_one = T::zero().checked_sub(T::one())?;
+        objective
+            .iter_mut()
+            .take(vars)
+            .for_each(|val| *val = minus_one);
+        tableau.push(objective);
+
+        Some(Self {
+            tableau,
+            basis: (vars..vars + rows).collect(),
+            vars,
+        })
+    }
+
+    // Pivots until optimum. The most negative reduced cost is taken first since it needs
+    // far fewer pivots on large games, Bland's rule takes over after a series of degenerate
+    // pivots, so cycling is impossible
+    fn run(&mut self) -> Option<()> {
+        let rows = self.basis.len();
+        let width = self.vars + rows;
+        let mut degenerate = 0;
+
+        loop {
+            let costs = &self.tableau[rows][..width];
+            let column = if degenerate < DEGENERATE_LIMIT {
+                costs
+                    .iter()
+                    .enumerate()
+                    .filter(|(_, cost)| cost.is_negative())
+                    .min_by(|(_, x), (_, y)| x.partial_cmp(y).unwrap())
+                    .map(|(j, _)| j)
+            } else {
+                costs.iter().position(|cost| cost.is_negative())
+            };
+            let column = match column {
+                Some(column) => column,
+                None => return Some(()),
+            };
+
+            let ratios = (0..rows)
+                .filter(|&i| self.tableau[i][column].is_positive())
+                .map(|i| {
+                    let ratio = self.tableau[i][width].checked_div(self.tableau[i][column])?;
+                    Some((i, ratio))
+                })
+                .collect::<Option<Vec<(usize, T)>>>()?;
+            let row = ratios
+                .iter()
+                .min_by(|(x, rx), (y, ry)| {
+                    rx.partial_cmp(ry)
+                        .unwrap()
+                        .then(self.basis[*x].cmp(&self.basis[*y]))
+                })?
+                .0; // Unbounded, can't happen with positive payoffs
+
+            if !self.tableau[row][width].is_positive() {
+                degenerate += 1;
+            }
+            self.pivot(row, column)?;
+        }
+    }
+
+    fn pivot(&mut self, row: usize, column: usize) -> Option<()> {
+        let pivot = self.tableau[row][column];
+        for val in self.tableau[row].iter_mut() {
+            *val = val.checked_div(pivot)?;
+        }
+
+        let pivot_row = self.tableau[row].clone();
+        for (i, current) in self.tableau.iter_mut().enumerate() {
+            let factor = current[column];
+            if i == row || (!factor.is_positive() && !factor.is_negative()) {
+                continue;
+            }
+            for (val, p) in current.iter_mut().zip(pivot_row.iter()) {
+                *val = val.checked_sub(factor.checked_mul(*p)?)?;
+            }
+        }
+
+        self.basis[row] = column;
+        Some(())
+    }
+
+    // Optimal value of the objective function
+    fn objective(&self) -> T {
+        *self.tableau.last().unwrap().last().unwrap()
+    }
+
+    // Value of the primal variable yj
+    fn primal(&self, j: usize) -> T {
+        self.basis
+            .iter()
+            .position(|&var| var == j)
+            .map_or(T::zero(), |i| *self.tableau[i].last().unwrap())
+    }
+
+    // Value of the dual variable xi (reduced cost of the i-th slack)
+    fn dual(&self, i: usize) -> T {
+        self.tableau.last().unwrap()[self.vars + i]
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::{super::integers, *};
+
+    #[test]
+    fn solves_in_fractions() {
+        let solution =
+            MixedSolution::solve(&integers(&[&[2, 6, 4], &[0, 0, 5], &[4, -4, 4]])).unwrap();
+        let exact = solution.exact.unwrap();
+        assert_eq!(exact.value, Rational::new(8, 3));
+        assert_eq!(
+            exact.a,
+            [
+                Rational::new(2, 3),
+                Rational::integer(0),
+                Rational::new(1, 3)
+            ]
+        );
+        assert_eq!(
+            exact.b,
+            [
+                Rational::new(5, 6),
+                Rational::new(1, 6),
+                Rational::integer(0)
+            ]
+        );
+        assert!((solution.value - 8.0 / 3.0).abs() < 1e-9);
+    }
+
+    #[test]
+    fn solves_matching_pennies() {
+        let exact = MixedSolution::solve(&integers(&[&[1, -1], &[-1, 1]]))
+            .unwrap()
+            .exact
+            .unwrap();
+        let half = Rational::new(1, 2);
+        assert_eq!(exact.value, Rational::integer(0));
+        assert_eq!(exact.a, [half, half]);
+        assert_eq!(exact.b, [half, half]);
+    }
+
+    #[test]
+    fn solves_floats_without_fractions() {
+        let matrix = vec![
+            vec![StoreType::Float(1.5), StoreType::Float(-1.0)],
+            vec![StoreType::Float(-1.0), StoreType::Float(1.0)],
+        ];
+        let solution = MixedSolution::solve(&matrix).unwrap();
+        assert!(solution.exact.is_none());
+        assert!((solution.value - 1.0 / 9.0).abs() < 1e-9);
+        assert!((solution.a[0] - 4.0 / 9.0).abs() < 1e-9);
+        assert!((solution.b[0] - 4.0 / 9.0).abs() < 1e-9);
+    }
+}
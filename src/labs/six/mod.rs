@@ -0,0 +1,1447 @@
+use std::ops::RangeInclusive;
+
+use super::{
+    divider, file, now, random_seed,
+    rational::Rational,
+    seeded_rng,
+    table::{Column, Row, Table},
+    viewer_toggle, SEED_RANGE, VALUE_LIMIT,
+};
+use bimatrix::Bimatrix;
+use brown_robinson::BrownRobinson;
+use dominance::{Player, Reduction};
+use eframe::{
+    egui::{
+        text::LayoutJob, Align, Button, CentralPanel, Color32, ComboBox, DragValue, Response,
+        RichText, ScrollArea, SidePanel, Slider, Stroke, TextFormat, TextStyle, Ui, Vec2,
+    },
+    epi::App,
+};
+use evaluator::Evaluation;
+use generator::Generator;
+use graphical::GraphicalSolution;
+use mixed::{ExactSolution, MixedSolution};
+use nature::{Nature, OBSERVATIONS_RANGE};
+use number::{Number, NumberKind};
+use preset::Preset;
+use replicator::Replicator;
+use report::ReportFormat;
+use sensitivity::Sensitivity;
+use statistics::Statistics;
+use storage::Format;
+use tree::{NodeKind, NormalForm, Tree};
+
+mod bimatrix;
+mod brown_robinson;
+mod dominance;
+mod evaluator;
+mod generator;
+mod graphical;
+mod mixed;
+mod nature;
+mod number;
+mod preset;
+mod replicator;
+mod report;
+mod sensitivity;
+mod statistics;
+mod storage;
+mod tree;
+mod windows;
+
+type StoreType = Number;
+const LENGTH_RANGE: RangeInclusive<usize> = 2..=300;
+
+// Matrix grid highlighting
+const SADDLE_COLOR: Color32 = Color32::DARK_GREEN;
+const MAX_MIN_COLOR: Color32 = Color32::LIGHT_BLUE;
+const MIN_MAX_COLOR: Color32 = Color32::LIGHT_RED;
+const OPTIMAL_COLOR: Color32 = Color32::LIGHT_GREEN;
+const PARETO_COLOR: Color32 = Color32::GOLD;
+const CRITICAL_COLOR: Color32 = Color32::RED;
+const SELECTED_COLOR: Color32 = Color32::YELLOW;
+
+// Largest visible heights of the matrix tables and the list of states probabilities
+const TABLE_HEIGHT: f32 = 400.0;
+const PROBABILITIES_HEIGHT: f32 = 160.0;
+
+// Largest game reduced and solved in mixed strategies on every edit, larger ones are on request
+const SOLVE_LIMIT: usize = 50;
+
+// Largest size of bimatrix games, equilibria enumeration grows exponentially
+const BIMATRIX_LIMIT: usize = 10;
+
+// Largest game analysed for sensitivity, every payoff is changed until the solution changes
+const SENSITIVITY_LIMIT: usize = 6;
+
+#[derive(Clone, Copy, Default, PartialEq)]
+enum Mode {
+    #[default]
+    Game,
+    Nature,
+    Bimatrix,
+}
+
+impl Mode {
+    const ALL: [Mode; 3] = [Mode::Game, Mode::Nature, Mode::Bimatrix];
+
+    fn as_str(&self) -> &str {
+        match self {
+            Mode::Game => "Matrix Game",
+            Mode::Nature => "Against Nature",
+            Mode::Bimatrix => "Bimatrix Game",
+        }
+    }
+}
+
+// -------------------------------------------------------------------------------------------------
+
+#[derive(Default, Debug)]
+struct Game {
+    pub matrix: Vec<Vec<StoreType>>,
+    pub matrix_b: Vec<Vec<StoreType>>, // Payoffs of B in the bimatrix game
+
+    // Calculation result
+    pub a_min: Vec<StoreType>,
+    pub b_max: Vec<StoreType>,
+    pub max_min: StoreType, // max(min(Ai))
+    pub min_max: StoreType, // min(max(Bj))
+    pub saddle_points: Vec<(usize, usize)>,
+    pub mixed: Option<Option<MixedSolution>>, // Calculated on demand, the inner None if not found
+    pub graphical: Option<GraphicalSolution>,
+    pub reduction: Option<Reduction>, // Calculated on demand, strategies are compared pairwise
+    pub brown_robinson: Option<BrownRobinson>, // Calculated on demand, while the window is open
+    pub iterations: usize,            // Brown-Robinson iterations count
+    pub nature: Nature,
+    pub bimatrix: Option<Bimatrix>, // Calculated on demand, equilibria enumeration is expensive
+    pub sensitivity: Option<Sensitivity>, // Calculated on demand, every payoff is solved many times
+    pub replicator: Option<Replicator>, // Last run of the population dynamics
+    pub report: Option<(ReportFormat, bool, String)>, // Last report with its format and fractions
+
+    pub kind: NumberKind,
+    pub value_range: (i64, i64), // Bounds of random values
+    pub seed: u64,               // Seed of random values
+    pub generator: Generator,    // Property of random games
+    pub dominated: usize,        // Dominated strategies count of the generator
+
+    pub rows: usize,
+    pub columns: usize,
+}
+
+impl Game {
+    const VALUE_RANGE: (i64, i64) = (2, 12);
+
+    // Changes the size of the inner matrix, freeing the old one and allocating new one
+    pub fn resize(&mut self, length: (usize, usize)) {
+        self.rows = length.0;
+        self.columns = length.1;
+
+        self.matrix = vec![vec![StoreType::default(); length.1]; length.0];
+        self.matrix_b = vec![vec![StoreType::default(); length.1]; length.0];
+        self.a_min = vec![StoreType::default(); length.0];
+        self.b_max = vec![StoreType::default(); length.1];
+
+        self.randomize();
+    }
+
+    // Replaces the inner matrix, the size is taken from the new one
+    pub fn set_matrix(&mut self, matrix: Vec<Vec<StoreType>>) {
+        self.rows = matrix.len();
+        self.columns = matrix.first().map_or(0, Vec::len);
+
+        if self.matrix_b.len() != self.rows || self.matrix_b[0].len() != self.columns {
+            self.matrix_b = vec![vec![StoreType::zero(self.kind); self.columns]; self.rows];
+        }
+        self.matrix = matrix;
+        self.a_min = vec![StoreType::default(); self.rows];
+        self.b_max = vec![StoreType::default(); self.columns];
+
+        self.recalc();
+    }
+
+    // Replaces both matrices by exact ones, values are kept integer if all of them are
+    pub fn set_rationals(&mut self, matrix_a: &[Vec<Rational>], matrix_b: &[Vec<Rational>]) {
+        let kind = if matrix_a
+            .iter()
+            .chain(matrix_b.iter())
+            .flatten()
+            .all(|val| val.denom() == 1)
+        {
+            NumberKind::Integer
+        } else {
+            NumberKind::Rational
+        };
+        let convert = |matrix: &[Vec<Rational>]| {
+            matrix
+                .iter()
+                .map(|row| {
+                    row.iter()
+                        .map(|&val| StoreType::Rational(val).convert(kind))
+                        .collect()
+                })
+                .collect::<Vec<Vec<StoreType>>>()
+        };
+
+        self.kind = kind;
+        self.matrix_b = convert(matrix_b);
+        self.set_matrix(convert(matrix_a));
+    }
+
+    // Parses matrix rows separated by new lines and values separated by tabs, spaces, commas or semicolons
+    pub fn parse(text: &str, kind: NumberKind) -> Result<Vec<Vec<StoreType>>, String> {
+        let matrix = text
+            .lines()
+            .filter(|line| !line.trim().is_empty())
+            .enumerate()
+            .map(|(i, line)| {
+                line.split(|c: char| c == ';' || c == ',' || c.is_whitespace())
+                    .filter(|val| !val.is_empty())
+                    .map(|val| {
+                        StoreType::parse(val, kind).map_err(|error| format!("{error} in row {i}"))
+                    })
+                    .collect::<Result<Vec<StoreType>, String>>()
+            })
+            .collect::<Result<Vec<Vec<StoreType>>, String>>()?;
+
+        if !LENGTH_RANGE.contains(&matrix.len()) {
+            return Err(format!(
+                "Rows count must be in range {}..={}",
+                LENGTH_RANGE.start(),
+                LENGTH_RANGE.end()
+            ));
+        }
+
+        let columns = matrix[0].len();
+        if !LENGTH_RANGE.contains(&columns) {
+            return Err(format!(
+                "Columns count must be in range {}..={}",
+                LENGTH_RANGE.start(),
+                LENGTH_RANGE.end()
+            ));
+        }
+        if let Some(i) = matrix.iter().position(|row| row.len() != columns) {
+            return Err(format!(
+                "Row {i} has {} values, expected {columns}",
+                matrix[i].len()
+            ));
+        }
+
+        Ok(matrix)
+    }
+
+    // Recalculate min(Ai) and max(Bj)
+    pub fn recalc(&mut self) {
+        self.a_min
+            .iter_mut()
+            .enumerate()
+            .for_each(|(i, val)| *val = *self.matrix.get(i).unwrap().iter().min().unwrap());
+
+        // Column maxima are found in a single pass over the rows
+        self.b_max.clone_from(&self.matrix[0]);
+        self.matrix.iter().skip(1).for_each(|row| {
+            self.b_max
+                .iter_mut()
+                .zip(row.iter())
+                .for_each(|(max, val)| *max = (*max).max(*val))
+        });
+
+        self.max_min = *self.a_min.iter().max().unwrap();
+        self.min_max = *self.b_max.iter().min().unwrap();
+
+        self.saddle_points.clear();
+        if self.max_min == self.min_max {
+            self.matrix.iter().enumerate().for_each(|(i, row)| {
+                row.iter().enumerate().for_each(|(j, val)| {
+                    if *val == self.max_min
+                        && self.a_min[i] == self.max_min
+                        && self.b_max[j] == self.min_max
+                    {
+                        self.saddle_points.push((i, j));
+                    }
+                })
+            });
+        }
+
+        self.graphical = GraphicalSolution::solve(&self.matrix);
+        self.nature.calc(&self.matrix);
+        self.reduction = None;
+        self.mixed = None;
+        self.brown_robinson = None;
+        self.bimatrix = None;
+        self.sensitivity = None;
+        self.replicator = None;
+        self.report = None;
+    }
+
+    // Game with the dominated strategies removed
+    pub fn reduction(&mut self) -> &Reduction {
+        self.reduction
+            .get_or_insert_with(|| Reduction::reduce(&self.matrix))
+    }
+
+    // Solution in mixed strategies of the reduced game expanded back to the whole game,
+    // None if the game has a saddle point or the solution isn't found
+    pub fn mixed(&mut self) -> Option<&MixedSolution> {
+        if !self.saddle_points.is_empty() {
+            return None;
+        }
+        if self.mixed.is_none() {
+            let (rows, columns) = (self.rows, self.columns);
+            let reduction = self.reduction();
+            let mixed = MixedSolution::solve(&reduction.matrix)
+                .map(|solution| reduction.expand(solution, rows, columns));
+            self.mixed = Some(mixed);
+        }
+        self.mixed.as_ref().and_then(Option::as_ref)
+    }
+
+    // Whether the reduction and the mixed solution are kept up to date while the matrix is edited
+    pub fn solved_on_edit(&self) -> bool {
+        self.rows <= SOLVE_LIMIT && self.columns <= SOLVE_LIMIT
+    }
+
+    pub fn brown_robinson(&mut self) -> &BrownRobinson {
+        self.brown_robinson
+            .get_or_insert_with(|| BrownRobinson::run(&self.matrix, self.iterations))
+    }
+
+    // Exact optimal strategies, pure ones if the saddle point exists
+    pub fn exact(&mut self) -> Option<MixedSolution> {
+        match self.saddle_points.first() {
+            Some(&(i, j)) => {
+                let mut a = vec![0.0; self.rows];
+                let mut b = vec![0.0; self.columns];
+                a[i] = 1.0;
+                b[j] = 1.0;
+                let exact = self.min_max.to_rational().map(|value| {
+                    let pure = |len: usize, k: usize| {
+                        (0..len)
+                            .map(|l| Rational::integer(i64::from(l == k)))
+                            .collect()
+                    };
+                    ExactSolution {
+                        a: pure(self.rows, i),
+                        b: pure(self.columns, j),
+                        value,
+                    }
+                });
+                Some(MixedSolution {
+                    a,
+                    b,
+                    value: self.min_max.to_f64(),
+                    exact,
+                })
+            }
+            None => self.mixed().cloned(),
+        }
+    }
+
+    // Randomize matrix values, the same seed always gives the same matrix
+    pub fn randomize(&mut self) {
+        let mut rng = seeded_rng(self.seed);
+
+        self.matrix
+            .iter_mut()
+            .chain(self.matrix_b.iter_mut())
+            .for_each(|row| {
+                row.iter_mut().for_each(|val| {
+                    *val = StoreType::random(&mut rng, self.kind, self.value_range);
+                })
+            });
+        self.generate(&mut rng);
+
+        self.recalc();
+    }
+
+    // Solution of the bimatrix game, (A, B) payoffs are (matrix, matrix_b),
+    // None if the game is too large to be solved
+    pub fn bimatrix(&mut self) -> Option<&Bimatrix> {
+        if self.rows > BIMATRIX_LIMIT || self.columns > BIMATRIX_LIMIT {
+            return None;
+        }
+        Some(
+            self.bimatrix
+                .get_or_insert_with(|| Bimatrix::solve(&self.matrix, &self.matrix_b)),
+        )
+    }
+
+    // Sensitivity of the solution to the payoffs with the game value curve of the cell,
+    // None if the game is too large to be analysed. The cell is clamped to the matrix
+    pub fn sensitivity(&mut self, cell: (usize, usize)) -> Option<&Sensitivity> {
+        if self.rows > SENSITIVITY_LIMIT || self.columns > SENSITIVITY_LIMIT {
+            return None;
+        }
+        let cell = (cell.0.min(self.rows - 1), cell.1.min(self.columns - 1));
+        let sensitivity = self
+            .sensitivity
+            .get_or_insert_with(|| Sensitivity::analyse(&self.matrix));
+        sensitivity.select(&self.matrix, cell);
+        Some(sensitivity)
+    }
+
+    // Population dynamics of the symmetric game, rerun only when the parameters change
+    pub fn replicator(&mut self, initial: &[f64], steps: usize, step: f64) -> &Replicator {
+        let outdated = self.replicator.as_ref().is_none_or(|replicator| {
+            replicator.initial != initial || replicator.steps != steps || replicator.step != step
+        });
+        if outdated {
+            self.replicator = Some(Replicator::run(&self.matrix, initial, steps, step));
+        }
+        self.replicator.as_ref().unwrap()
+    }
+
+    // Sets all matrix values to zero
+    pub fn clear(&mut self) {
+        self.matrix
+            .iter_mut()
+            .chain(self.matrix_b.iter_mut())
+            .for_each(|row| {
+                row.iter_mut()
+                    .for_each(|val| *val = StoreType::zero(self.kind))
+            });
+
+        self.recalc();
+    }
+
+    // Converts all matrix values to another number kind
+    pub fn set_kind(&mut self, kind: NumberKind) {
+        self.kind = kind;
+        self.matrix
+            .iter_mut()
+            .chain(self.matrix_b.iter_mut())
+            .for_each(|row| row.iter_mut().for_each(|val| *val = val.convert(kind)));
+
+        self.recalc();
+    }
+}
+
+// Analyses the game given as CSV, JSON or plain text matrix without the UI,
+// returns human-readable results or the JSON document with the results.
+// Exact solutions are written as fractions unless decimals are asked for
+pub fn solve(text: &str, json: bool, fractions: bool) -> Result<String, String> {
+    let mut game = Game::default();
+    game.load(text)?;
+
+    Ok(if json {
+        game.save(Format::Json)
+    } else {
+        game.summary(fractions)
+    })
+}
+
+// Matrix of integer payoffs for the tests of the solvers
+#[cfg(test)]
+fn integers(matrix: &[&[i64]]) -> Vec<Vec<StoreType>> {
+    matrix
+        .iter()
+        .map(|row| row.iter().map(|&val| StoreType::Integer(val)).collect())
+        .collect()
+}
+
+// Index text formatting
+#[inline]
+fn index_text(prefix: &str, index: usize) -> LayoutJob {
+    let mut job = LayoutJob::single_section(prefix.to_string(), TextFormat::default());
+    job.append(
+        index.to_string().as_str(),
+        0.0,
+        TextFormat {
+            style: TextStyle::Small,
+            valign: Align::BOTTOM,
+            ..Default::default()
+        },
+    );
+    job
+}
+
+// Editor widget for a single payoff value
+fn number_edit(ui: &mut Ui, value: &mut Number) -> Response {
+    match value {
+        Number::Integer(val) => ui.add(DragValue::new(val).speed(0.1)),
+        Number::Float(val) => ui.add(DragValue::new(val).speed(0.01).max_decimals(4)),
+        Number::Rational(val) => {
+            let (mut num, mut den) = (val.numer(), val.denom());
+            let response = ui
+                .horizontal(|horiz| {
+                    horiz.spacing_mut().item_spacing.x = 2.0;
+                    let response = horiz.add(DragValue::new(&mut num).speed(0.1));
+                    horiz.label("/");
+                    response
+                        | horiz.add(
+                            DragValue::new(&mut den)
+                                .speed(0.1)
+                                .clamp_range(1..=i64::MAX),
+                        )
+                })
+                .inner;
+            if response.changed() {
+                *val = Rational::new(num, den);
+            }
+            response
+        }
+    }
+}
+
+// Width of the matrix cell fitting the value editor
+fn cell_width(kind: NumberKind) -> f32 {
+    match kind {
+        NumberKind::Integer => 56.0,
+        NumberKind::Float => 72.0,
+        NumberKind::Rational => 104.0,
+    }
+}
+
+// Top left corner of the matrix grid
+fn corner_text() -> LayoutJob {
+    let mut job = LayoutJob::default();
+    job.append(
+        "A",
+        0.0,
+        TextFormat {
+            style: TextStyle::Small,
+            valign: Align::BOTTOM,
+            ..Default::default()
+        },
+    );
+    job.append(" / ", 0.0, Default::default());
+    job.append(
+        "B",
+        0.0,
+        TextFormat {
+            style: TextStyle::Small,
+            valign: Align::TOP,
+            ..Default::default()
+        },
+    );
+    job
+}
+
+// Formats value with up to 4 decimals, omitting trailing zeros
+fn format_value(value: f64) -> String {
+    let text = format!("{value:.4}");
+    let text = text.trim_end_matches('0').trim_end_matches('.');
+    match text {
+        "-0" => "0".to_string(),
+        text => text.to_string(),
+    }
+}
+
+// Formats vector of probabilities as (p1, p2, ...)
+fn format_probabilities(probabilities: &[f64]) -> String {
+    format!(
+        "({})",
+        probabilities
+            .iter()
+            .map(|p| format!("{p:.4}"))
+            .collect::<Vec<String>>()
+            .join(", ")
+    )
+}
+
+// Formats vector of exact probabilities as (p1, p2, ...) of reduced fractions
+fn format_fractions(probabilities: &[Rational]) -> String {
+    format!(
+        "({})",
+        probabilities
+            .iter()
+            .map(Rational::to_string)
+            .collect::<Vec<String>>()
+            .join(", ")
+    )
+}
+
+pub struct SixthLab {
+    game: Game,
+    length: (usize, usize),
+    mode: Mode,
+
+    // UI
+    brown_robinson_viewer: bool,
+    graphical_viewer: bool,
+    statistics_viewer: bool,
+    statistics: Statistics,
+    evaluator_viewer: bool,
+    evaluator_player: Player,
+    evaluator_buffer: String,
+    sensitivity_viewer: bool,
+    sensitivity_cell: (usize, usize), // Cell of the game value curve
+    replicator_viewer: bool,
+    replicator_buffer: String, // Initial shares of the population
+    replicator_steps: usize,
+    replicator_step: f64,
+    tree_viewer: bool,
+    tree: Tree,
+    tree_node: usize,                                // Selected node
+    tree_result: Option<Result<NormalForm, String>>, // Last conversion to the matrix
+    paste_viewer: bool,
+    paste_buffer: String,
+    paste_error: Option<String>,
+    library_viewer: bool,
+    preset: Preset,
+    preset_parameters: Vec<usize>, // Sizes of the parametrized family
+    report_viewer: bool,
+    report_format: ReportFormat,
+    fractions: bool,  // Exact solutions are shown as fractions
+    experiment: bool, // Decision against nature may be made after the experiment
+    upload: file::Upload,
+    file_error: Option<String>,
+}
+
+impl App for SixthLab {
+    fn update(&mut self, ctx: &eframe::egui::CtxRef, frame: &eframe::epi::Frame) {
+        if let Some(loaded) = self.upload.take() {
+            match loaded.and_then(|text| self.game.load(&text)) {
+                Ok(()) => {
+                    self.length = (self.game.rows, self.game.columns);
+                    self.sensitivity_cell = (0, 0);
+                    self.file_error = None;
+                }
+                Err(error) => self.file_error = Some(error),
+            }
+        }
+
+        // Side panel
+        SidePanel::left("left_panel")
+            .max_width(256.0)
+            .show(ctx, |ui| {
+                ui.vertical_centered(|vert| vert.heading("⛭ Controls"));
+                ui.separator();
+                ui.vertical_centered(|vert| vert.label("Matrix size"));
+                ui.vertical(|vert| {
+                    vert.add_sized(
+                        Vec2::new(vert.available_size_before_wrap().x, 1.0),
+                        DragValue::new(&mut self.length.0)
+                            .speed(0.05)
+                            .prefix("M: ")
+                            .clamp_range(LENGTH_RANGE),
+                    );
+                    vert.add_sized(
+                        Vec2::new(vert.available_size_before_wrap().x, 1.0),
+                        DragValue::new(&mut self.length.1)
+                            .speed(0.05)
+                            .prefix("N: ")
+                            .clamp_range(LENGTH_RANGE),
+                    );
+                });
+                ui.vertical_centered(|vert| vert.label("Values"));
+                ui.vertical(|vert| {
+                    let mut kind = self.game.kind;
+                    ComboBox::from_id_source("number_kind")
+                        .width(vert.available_size_before_wrap().x)
+                        .selected_text(kind.as_str())
+                        .show_ui(vert, |combo| {
+                            NumberKind::ALL.iter().for_each(|option| {
+                                combo.selectable_value(&mut kind, *option, option.as_str());
+                            });
+                        });
+                    if kind != self.game.kind {
+                        self.game.set_kind(kind);
+                    }
+
+                    let (min, max) = &mut self.game.value_range;
+                    vert.add_sized(
+                        Vec2::new(vert.available_size_before_wrap().x, 1.0),
+                        DragValue::new(min)
+                            .speed(0.1)
+                            .prefix("Min: ")
+                            .clamp_range(*VALUE_LIMIT.start()..=*max),
+                    );
+                    vert.add_sized(
+                        Vec2::new(vert.available_size_before_wrap().x, 1.0),
+                        DragValue::new(max)
+                            .speed(0.1)
+                            .prefix("Max: ")
+                            .clamp_range(*min..=*VALUE_LIMIT.end()),
+                    );
+                    if vert
+                        .add_sized(
+                            Vec2::new(vert.available_size_before_wrap().x, 1.0),
+                            DragValue::new(&mut self.game.seed)
+                                .speed(0.1)
+                                .prefix("Seed: ")
+                                .clamp_range(SEED_RANGE),
+                        )
+                        .changed()
+                    {
+                        self.game.randomize();
+                    }
+                });
+                ui.vertical_centered(|vert| vert.label("Mode"));
+                ComboBox::from_id_source("lab_mode")
+                    .width(ui.available_size_before_wrap().x)
+                    .selected_text(self.mode.as_str())
+                    .show_ui(ui, |combo| {
+                        Mode::ALL.iter().for_each(|mode| {
+                            combo.selectable_value(&mut self.mode, *mode, mode.as_str());
+                        });
+                    });
+                if self.mode == Mode::Nature {
+                    divider(ui);
+                    ui.vertical_centered(|vert| vert.heading("🌦 Nature"));
+                    ui.separator();
+                    let nature = &mut self.game.nature;
+                    let mut changed = ui.checkbox(&mut nature.loss, "Loss table").changed();
+                    changed |= ui
+                        .add(Slider::new(&mut nature.alpha, 0.0..=1.0).text("Hurwicz α"))
+                        .changed();
+                    ui.label("States probabilities");
+                    let count = nature.probabilities.len();
+                    ScrollArea::vertical()
+                        .id_source("probabilities")
+                        .max_height(PROBABILITIES_HEIGHT)
+                        .show_rows(ui, ui.spacing().interact_size.y, count, |scroll, range| {
+                            nature.probabilities[range.clone()]
+                                .iter_mut()
+                                .zip(range)
+                                .for_each(|(p, j)| {
+                                    changed |= scroll
+                                        .add_sized(
+                                            Vec2::new(scroll.available_size_before_wrap().x, 1.0),
+                                            DragValue::new(p)
+                                                .speed(0.01)
+                                                .prefix(format!("P(B{j}): "))
+                                                .clamp_range(0.0..=1.0),
+                                        )
+                                        .changed();
+                                });
+                        });
+                    let total = nature.probabilities.iter().sum::<f64>();
+                    if total <= 0.0 {
+                        ui.colored_label(Color32::YELLOW, "Sum is 0, states are equally likely");
+                    } else if (total - 1.0).abs() > 1e-9 {
+                        ui.colored_label(
+                            Color32::YELLOW,
+                            format!("Sum is {}, weights are normalized", format_value(total)),
+                        );
+                    }
+                    ui.checkbox(&mut self.experiment, "Costly experiment");
+                    if self.experiment {
+                        let experiment = &mut nature.experiment;
+                        changed |= ui
+                            .add_sized(
+                                Vec2::new(ui.available_size_before_wrap().x, 1.0),
+                                DragValue::new(&mut experiment.observations)
+                                    .speed(0.05)
+                                    .prefix("Observations: ")
+                                    .clamp_range(OBSERVATIONS_RANGE),
+                            )
+                            .changed();
+                        changed |= ui
+                            .add_sized(
+                                Vec2::new(ui.available_size_before_wrap().x, 1.0),
+                                DragValue::new(&mut experiment.cost)
+                                    .speed(0.1)
+                                    .prefix("Cost: ")
+                                    .clamp_range(0.0..=*VALUE_LIMIT.end() as f64),
+                            )
+                            .changed();
+                    }
+                    if changed {
+                        nature.calc(&self.game.matrix);
+                    }
+                }
+                divider(ui);
+                ui.vertical_centered(|vert| vert.heading("✔ Results"));
+                ui.separator();
+                if self.mode == Mode::Bimatrix {
+                    match self.game.bimatrix() {
+                        Some(bimatrix) => {
+                            ui.label(format!("Nash equilibria: {}", bimatrix.equilibria.len()));
+                            bimatrix.equilibria.iter().for_each(|equilibrium| {
+                                ui.separator();
+                                match equilibrium.pure() {
+                                    Some(point) => ui.label(format!("Pure: {point:?}")),
+                                    None => ui.label("Mixed"),
+                                };
+                                ui.label(format!("P = {}", format_probabilities(&equilibrium.a)));
+                                ui.label(format!("Q = {}", format_probabilities(&equilibrium.b)));
+                                ui.label(format!(
+                                    "(Va, Vb) = ({}, {})",
+                                    format_value(equilibrium.payoff.0),
+                                    format_value(equilibrium.payoff.1)
+                                ));
+                            });
+                        }
+                        None => {
+                            ui.label(format!(
+                                "Bimatrix games are solved up to {BIMATRIX_LIMIT}x{BIMATRIX_LIMIT}"
+                            ));
+                        }
+                    }
+                } else if self.mode == Mode::Nature {
+                    self.game.nature.criteria.iter().for_each(|criterion| {
+                        ui.label(format!(
+                            "{}: {}",
+                            criterion.kind.as_str(),
+                            criterion
+                                .optimal
+                                .iter()
+                                .map(|i| format!("A{i}"))
+                                .collect::<Vec<String>>()
+                                .join(", ")
+                        ));
+                    });
+                } else {
+                    ui.vertical(|vert| {
+                        vert.label(format!("max(min(Ai)) = {}", self.game.max_min));
+                        vert.label(format!("min(max(Bj)) = {}", self.game.min_max));
+                        match self.game.saddle_points.as_slice() {
+                            [] => {
+                                vert.label("No Pure Strategy Solutions");
+                                if self.game.mixed.is_none() && !self.game.solved_on_edit() {
+                                    if vert
+                                        .button("Solve in Mixed Strategies")
+                                        .on_hover_text(format!(
+                                            "Games larger than {SOLVE_LIMIT}x{SOLVE_LIMIT} are solved on request"
+                                        ))
+                                        .clicked()
+                                    {
+                                        self.game.mixed();
+                                    }
+                                } else if let Some(mixed) = self.game.mixed() {
+                                    vert.separator();
+                                    vert.label("Mixed Strategy Solution");
+                                    let (value, p, q) =
+                                        match mixed.exact.as_ref().filter(|_| self.fractions) {
+                                            Some(exact) => (
+                                                exact.value.to_string(),
+                                                format_fractions(&exact.a),
+                                                format_fractions(&exact.b),
+                                            ),
+                                            None => (
+                                                format!("{:.4}", mixed.value),
+                                                format_probabilities(&mixed.a),
+                                                format_probabilities(&mixed.b),
+                                            ),
+                                        };
+                                    vert.label(format!("V = {value}"));
+                                    vert.label(format!("P = {p}"));
+                                    vert.label(format!("Q = {q}"));
+                                    if mixed.exact.is_some() {
+                                        vert.checkbox(&mut self.fractions, "Fractions")
+                                            .on_hover_text("Decimals are shown otherwise");
+                                    }
+                                }
+                            }
+                            points => {
+                                vert.label(format!("V = {}", self.game.min_max));
+                                vert.label(format!(
+                                    "Saddle Point{}: {}",
+                                    if points.len() > 1 { "s" } else { "" },
+                                    points
+                                        .iter()
+                                        .map(|point| format!("{point:?}"))
+                                        .collect::<Vec<String>>()
+                                        .join(", ")
+                                ));
+                            }
+                        }
+                    });
+                }
+                divider(ui);
+                ui.vertical_centered(|vert| vert.heading("👁 Viewers"));
+                ui.separator();
+                ui.vertical(|vert| {
+                    viewer_toggle(vert, &mut self.brown_robinson_viewer, "Brown-Robinson Method");
+                    viewer_toggle(vert, &mut self.graphical_viewer, "Graphical Method");
+                    viewer_toggle(vert, &mut self.statistics_viewer, "Statistics");
+                    viewer_toggle(vert, &mut self.evaluator_viewer, "Strategy Evaluator");
+                    viewer_toggle(vert, &mut self.sensitivity_viewer, "Sensitivity Analysis");
+                    viewer_toggle(vert, &mut self.replicator_viewer, "Replicator Dynamics");
+                    viewer_toggle(vert, &mut self.tree_viewer, "Game Tree Editor");
+                });
+                divider(ui);
+                ui.vertical_centered(|vert| vert.heading("🔃 Results"));
+                ui.separator();
+                if ui
+                    .add_sized(
+                        Vec2::new(ui.available_size().x, 1.0),
+                        Button::new("Regenerate"),
+                    )
+                    .clicked()
+                {
+                    self.game.resize(self.length);
+                    self.sensitivity_cell = (0, 0);
+                }
+                if ui
+                    .add_sized(
+                        Vec2::new(ui.available_size().x, 1.0),
+                        Button::new("Randomize"),
+                    )
+                    .clicked()
+                {
+                    self.game.seed = random_seed();
+                    self.game.randomize();
+                }
+                let generator = self.game.generator;
+                ComboBox::from_id_source("generator")
+                    .width(ui.available_size_before_wrap().x)
+                    .selected_text(generator.as_str())
+                    .show_ui(ui, |combo| {
+                        Generator::ALL.iter().for_each(|option| {
+                            combo.selectable_value(
+                                &mut self.game.generator,
+                                *option,
+                                option.as_str(),
+                            );
+                        });
+                    });
+                if self.game.generator == Generator::Dominated
+                    && ui
+                        .add_sized(
+                            Vec2::new(ui.available_size_before_wrap().x, 1.0),
+                            DragValue::new(&mut self.game.dominated)
+                                .speed(0.05)
+                                .prefix("Dominated: ")
+                                .clamp_range(0..=self.game.rows + self.game.columns - 2),
+                        )
+                        .changed()
+                    || self.game.generator != generator
+                {
+                    self.game.randomize();
+                }
+                if ui
+                    .add_sized(Vec2::new(ui.available_size().x, 1.0), Button::new("Clear"))
+                    .clicked()
+                {
+                    self.game.clear();
+                }
+                if ui
+                    .add_sized(Vec2::new(ui.available_size().x, 1.0), Button::new("Paste"))
+                    .on_hover_text("Paste matrix from clipboard")
+                    .clicked()
+                {
+                    self.paste_viewer = !self.paste_viewer;
+                }
+                if ui
+                    .add_sized(
+                        Vec2::new(ui.available_size().x, 1.0),
+                        Button::new("Library"),
+                    )
+                    .on_hover_text("Load a well-known game")
+                    .clicked()
+                {
+                    self.library_viewer = !self.library_viewer;
+                }
+                if ui
+                    .add_sized(Vec2::new(ui.available_size().x, 1.0), Button::new("Report"))
+                    .on_hover_text("Step-by-step solution in Markdown or LaTeX")
+                    .clicked()
+                {
+                    self.report_viewer = !self.report_viewer;
+                }
+                ui.horizontal(|hor| {
+                    for format in Format::ALL {
+                        if hor
+                            .button(format!("Save {}", format.as_str()))
+                            .on_hover_text("Save matrix to file")
+                            .clicked()
+                        {
+                            let name = format!("game.{}", format.extension());
+                            if let Err(error) = file::save(&name, &self.game.save(format)) {
+                                self.file_error = Some(error);
+                            }
+                        }
+                    }
+                    if hor
+                        .button("Load")
+                        .on_hover_text("Load matrix from CSV or JSON file")
+                        .clicked()
+                    {
+                        let extensions = Format::ALL
+                            .iter()
+                            .map(Format::extension)
+                            .collect::<Vec<&str>>();
+                        self.upload.open(frame, &extensions);
+                    }
+                });
+                if let Some(error) = &self.file_error {
+                    ui.colored_label(Color32::RED, error);
+                }
+            });
+
+        self.windows(ctx);
+
+        // Main section
+        CentralPanel::default().show(ctx, |ui| {
+            ui.label("Matrix View");
+            ScrollArea::vertical().show(ui, |scroll| {
+                let width = cell_width(self.game.kind);
+
+                if self.mode == Mode::Bimatrix {
+                    // Bimatrix table
+                    let (pure, pareto) = match self.game.bimatrix() {
+                        Some(bimatrix) => (
+                            bimatrix
+                                .equilibria
+                                .iter()
+                                .filter_map(|equilibrium| equilibrium.pure())
+                                .collect::<Vec<(usize, usize)>>(),
+                            bimatrix.pareto.clone(),
+                        ),
+                        None => (Vec::new(), Vec::new()),
+                    };
+                    // Payoffs of B matter only for the bimatrix solution
+                    let (mut changed, mut changed_b) = (false, false);
+                    Table::new("bimatrix", self.game.rows, self.game.columns)
+                        .cell_width(2.0 * width + 16.0)
+                        .max_height(TABLE_HEIGHT)
+                        .show(scroll, |cell, row, column| match (row, column) {
+                            (Row::Header, Column::Label) => {
+                                cell.label(corner_text());
+                            }
+                            (Row::Header, Column::Body(j)) => {
+                                cell.label(index_text("B", j));
+                            }
+                            (Row::Body(i), Column::Label) => {
+                                cell.label(index_text("A", i));
+                            }
+                            (Row::Body(i), Column::Body(j)) => {
+                                let response = number_edit(cell, &mut self.game.matrix[i][j]);
+                                changed |= response.changed();
+                                cell.label(",");
+                                let response_b = number_edit(cell, &mut self.game.matrix_b[i][j]);
+                                changed_b |= response_b.changed();
+                                let response = response | response_b;
+                                if pure.contains(&(i, j)) {
+                                    cell.painter().rect_stroke(
+                                        response.rect.expand(1.0),
+                                        2.0,
+                                        Stroke::new(2.0, SADDLE_COLOR),
+                                    );
+                                }
+                                if pareto.contains(&(i, j)) {
+                                    cell.painter().rect_stroke(
+                                        response.rect.expand(3.0),
+                                        2.0,
+                                        Stroke::new(1.0, PARETO_COLOR),
+                                    );
+                                }
+                            }
+                            (Row::Footer, _) => {}
+                        });
+                    if changed {
+                        self.game.recalc();
+                    } else if changed_b {
+                        self.game.bimatrix = None;
+                    }
+
+                    scroll.add_space(16.0);
+                    scroll.colored_label(SADDLE_COLOR, "■ Pure Nash equilibrium");
+                    scroll.colored_label(PARETO_COLOR, "■ Pareto-optimal outcome");
+                    return;
+                }
+
+                // Large games are reduced along with the mixed solution on request
+                let reduced = (self.game.solved_on_edit() || self.game.reduction.is_some())
+                    && !self.game.reduction().steps.is_empty();
+                scroll.horizontal_top(|horiz| {
+                    // Both matrices share the width if the game was reduced
+                    let max_width = if reduced {
+                        (horiz.available_width() - horiz.spacing().item_spacing.x * 2.0) / 2.0
+                    } else {
+                        horiz.available_width()
+                    };
+
+                    // Closeness of every payoff to changing the solution is shown over the matrix
+                    let heatmap = if self.sensitivity_viewer {
+                        self.game
+                            .sensitivity(self.sensitivity_cell)
+                            .map(|sensitivity| {
+                                (sensitivity.critical.clone(), sensitivity.bounds.clone())
+                            })
+                    } else {
+                        None
+                    };
+
+                    // Matrix table, the last column is min(Ai)
+                    let mut changed = false;
+                    let game = &mut self.game;
+                    let selected = &mut self.sensitivity_cell;
+                    Table::new("matrix", game.rows, game.columns + 1)
+                        .footer(true)
+                        .cell_width(width)
+                        .max_width(max_width)
+                        .max_height(TABLE_HEIGHT)
+                        .show(horiz, |cell, row, column| match (row, column) {
+                            (Row::Header, Column::Label) => {
+                                cell.label(corner_text());
+                            }
+                            (Row::Header, Column::Body(j)) if j == game.columns => {
+                                cell.label("min(Ai)");
+                            }
+                            (Row::Header, Column::Body(j)) => {
+                                cell.label(index_text("B", j));
+                            }
+                            (Row::Body(i), Column::Label) => {
+                                cell.label(index_text("A", i));
+                            }
+                            (Row::Body(i), Column::Body(j)) if j == game.columns => {
+                                let min = game.a_min[i];
+                                let text = RichText::new(min.to_string());
+                                cell.label(if min == game.max_min {
+                                    text.strong().color(MAX_MIN_COLOR)
+                                } else {
+                                    text
+                                });
+                            }
+                            (Row::Body(i), Column::Body(j)) => {
+                                let mut response = number_edit(cell, &mut game.matrix[i][j]);
+                                changed |= response.changed();
+                                if let Some((critical, bounds)) = &heatmap {
+                                    cell.painter().rect_filled(
+                                        response.rect.expand(1.0),
+                                        2.0,
+                                        CRITICAL_COLOR.linear_multiply(critical[i][j] as f32 * 0.5),
+                                    );
+                                    if (i, j) == *selected {
+                                        cell.painter().rect_stroke(
+                                            response.rect.expand(3.0),
+                                            2.0,
+                                            Stroke::new(1.0, SELECTED_COLOR),
+                                        );
+                                    }
+                                    let (low, high) = bounds[i][j];
+                                    response = response.on_hover_text(format!(
+                                        "Solution is kept in [{}, {}], right click to plot V",
+                                        low.map_or("-∞".to_string(), format_value),
+                                        high.map_or("+∞".to_string(), format_value)
+                                    ));
+                                    if response.secondary_clicked() {
+                                        *selected = (i, j);
+                                    }
+                                }
+                                if game.saddle_points.contains(&(i, j)) {
+                                    cell.painter().rect_stroke(
+                                        response.rect.expand(1.0),
+                                        2.0,
+                                        Stroke::new(2.0, SADDLE_COLOR),
+                                    );
+                                }
+                            }
+                            (Row::Footer, Column::Label) => {
+                                cell.label("max(Bj)");
+                            }
+                            (Row::Footer, Column::Body(j)) if j == game.columns => {}
+                            (Row::Footer, Column::Body(j)) => {
+                                let max = game.b_max[j];
+                                let text = RichText::new(max.to_string());
+                                cell.label(if max == game.min_max {
+                                    text.strong().color(MIN_MAX_COLOR)
+                                } else {
+                                    text
+                                });
+                            }
+                        });
+                    if changed {
+                        self.game.recalc();
+                    }
+
+                    // Reduced matrix table, left out until the next frame if the matrix was edited
+                    if let Some(reduction) = self.game.reduction.as_ref().filter(|_| reduced) {
+                        horiz.separator();
+                        Table::new(
+                            "reduced_matrix",
+                            reduction.rows.len(),
+                            reduction.columns.len(),
+                        )
+                        .cell_width(width)
+                        .max_width(max_width)
+                        .max_height(TABLE_HEIGHT)
+                        .show(horiz, |cell, row, column| {
+                            match (row, column) {
+                                (Row::Header, Column::Label) => {
+                                    cell.label(corner_text());
+                                }
+                                (Row::Header, Column::Body(j)) => {
+                                    cell.label(index_text("B", reduction.columns[j]));
+                                }
+                                (Row::Body(i), Column::Label) => {
+                                    cell.label(index_text("A", reduction.rows[i]));
+                                }
+                                (Row::Body(i), Column::Body(j)) => {
+                                    cell.label(reduction.matrix[i][j].to_string());
+                                }
+                                (Row::Footer, _) => {}
+                            }
+                        });
+                    }
+                });
+
+                // Dominance log
+                if let Some(reduction) = self.game.reduction.as_ref().filter(|_| reduced) {
+                    scroll.add_space(16.0);
+                    scroll.label("Dominance Reduction");
+                    reduction.steps.iter().enumerate().for_each(|(i, step)| {
+                        scroll.label(format!("{}. {step}", i + 1));
+                    });
+                }
+
+                // Decision criteria
+                if self.mode == Mode::Nature {
+                    let nature = &self.game.nature;
+
+                    scroll.add_space(16.0);
+                    scroll.label("Decision Criteria");
+                    Table::new("nature_criteria", self.game.rows, nature.criteria.len())
+                        .footer(true)
+                        .cell_width(80.0)
+                        .max_height(TABLE_HEIGHT)
+                        .show(scroll, |cell, row, column| match (row, column) {
+                            (Row::Header, Column::Label) => {}
+                            (Row::Header, Column::Body(k)) => {
+                                cell.label(nature.criteria[k].kind.as_str());
+                            }
+                            (Row::Body(i), Column::Label) => {
+                                cell.label(index_text("A", i));
+                            }
+                            (Row::Body(i), Column::Body(k)) => {
+                                let criterion = &nature.criteria[k];
+                                let text = RichText::new(format_value(criterion.values[i]));
+                                cell.label(if criterion.optimal.contains(&i) {
+                                    text.strong().color(OPTIMAL_COLOR)
+                                } else {
+                                    text
+                                });
+                            }
+                            (Row::Footer, Column::Label) => {
+                                cell.label("Optimal");
+                            }
+                            (Row::Footer, Column::Body(k)) => {
+                                let optimal = nature.criteria[k]
+                                    .optimal
+                                    .iter()
+                                    .map(|i| format!("A{i}"))
+                                    .collect::<Vec<String>>()
+                                    .join(", ");
+                                cell.label(optimal.as_str()).on_hover_text(optimal);
+                            }
+                        });
+
+                    scroll.add_space(16.0);
+                    scroll.label("Regret Matrix");
+                    Table::new("nature_regret", self.game.rows, self.game.columns)
+                        .max_height(TABLE_HEIGHT)
+                        .show(scroll, |cell, row, column| match (row, column) {
+                            (Row::Header, Column::Label) => {
+                                cell.label(corner_text());
+                            }
+                            (Row::Header, Column::Body(j)) => {
+                                cell.label(index_text("B", j));
+                            }
+                            (Row::Body(i), Column::Label) => {
+                                cell.label(index_text("A", i));
+                            }
+                            (Row::Body(i), Column::Body(j)) => {
+                                cell.label(format_value(nature.regret[i][j]));
+                            }
+                            (Row::Footer, _) => {}
+                        });
+
+                    if !self.experiment {
+                        return;
+                    }
+                    let columns = self.game.columns;
+                    let observations = nature.experiment.observations;
+                    let outcome = if nature.loss { "loss" } else { "payoff" };
+
+                    scroll.add_space(16.0);
+                    scroll.label("Experiment Likelihood P(Ok | Bj)");
+                    let mut changed = false;
+                    let experiment = &mut self.game.nature.experiment;
+                    Table::new("nature_likelihood", observations, columns)
+                        .footer(true)
+                        .max_height(TABLE_HEIGHT)
+                        .show(scroll, |cell, row, column| match (row, column) {
+                            (Row::Header, Column::Label) => {
+                                cell.label(corner_text());
+                            }
+                            (Row::Header, Column::Body(j)) => {
+                                cell.label(index_text("B", j));
+                            }
+                            (Row::Body(k), Column::Label) => {
+                                cell.label(index_text("O", k));
+                            }
+                            (Row::Body(k), Column::Body(j)) => {
+                                changed |= cell
+                                    .add(
+                                        DragValue::new(&mut experiment.likelihood[k][j])
+                                            .speed(0.01)
+                                            .clamp_range(0.0..=1.0),
+                                    )
+                                    .changed();
+                            }
+                            (Row::Footer, Column::Label) => {
+                                cell.label("Sum");
+                            }
+                            (Row::Footer, Column::Body(j)) => {
+                                let sum = experiment.likelihood.iter().map(|row| row[j]).sum();
+                                cell.label(format_value(sum));
+                            }
+                        });
+                    if experiment.unnormalized {
+                        scroll.colored_label(
+                            Color32::YELLOW,
+                            "Some columns don't sum to 1, they are normalized",
+                        );
+                    }
+                    if changed {
+                        self.game.nature.calc(&self.game.matrix);
+                    }
+
+                    let experiment = &self.game.nature.experiment;
+                    scroll.add_space(16.0);
+                    scroll.label(format!(
+                        "Expected {outcome} without experiment: {}",
+                        format_value(experiment.prior_value)
+                    ));
+                    scroll.label(format!(
+                        "Expected {outcome} with perfect information: {}, EVPI = {}",
+                        format_value(experiment.perfect_value),
+                        format_value(experiment.evpi)
+                    ));
+                    scroll.label(format!(
+                        "Expected {outcome} with experiment: {}, EVSI = {}",
+                        format_value(experiment.sample_value),
+                        format_value(experiment.evsi)
+                    ));
+                    let gain = experiment.net_gain();
+                    scroll.colored_label(
+                        if gain > 0.0 {
+                            OPTIMAL_COLOR
+                        } else {
+                            Color32::RED
+                        },
+                        format!(
+                            "Net gain EVSI - cost = {}, the experiment {}",
+                            format_value(gain),
+                            if gain > 0.0 {
+                                "pays off"
+                            } else {
+                                "doesn't pay off"
+                            }
+                        ),
+                    );
+
+                    scroll.add_space(16.0);
+                    scroll.label("Posterior Probabilities P(Bj | Ok)");
+                    Table::new("nature_posteriors", observations, columns + 1)
+                        .cell_width(80.0)
+                        .max_height(TABLE_HEIGHT)
+                        .show(scroll, |cell, row, column| match (row, column) {
+                            (Row::Header, Column::Label) => {
+                                cell.label(corner_text());
+                            }
+                            (Row::Header, Column::Body(0)) => {
+                                cell.label("P(Ok)");
+                            }
+                            (Row::Header, Column::Body(j)) => {
+                                cell.label(index_text("B", j - 1));
+                            }
+                            (Row::Body(k), Column::Label) => {
+                                cell.label(index_text("O", k));
+                            }
+                            (Row::Body(k), Column::Body(0)) => {
+                                cell.label(format_value(experiment.evidence[k]));
+                            }
+                            (Row::Body(k), Column::Body(j)) => {
+                                cell.label(format_value(experiment.posteriors[k][j - 1]));
+                            }
+                            (Row::Footer, _) => {}
+                        });
+
+                    scroll.add_space(16.0);
+                    scroll.label(format!("Decision Table, expected {outcome} after Ok"));
+                    Table::new("nature_decisions", self.game.rows, observations)
+                        .footer(true)
+                        .cell_width(80.0)
+                        .max_height(TABLE_HEIGHT)
+                        .show(scroll, |cell, row, column| match (row, column) {
+                            (Row::Header, Column::Label) => {
+                                cell.label(corner_text());
+                            }
+                            (Row::Header, Column::Body(k)) => {
+                                cell.label(index_text("O", k));
+                            }
+                            (Row::Body(i), Column::Label) => {
+                                cell.label(index_text("A", i));
+                            }
+                            (Row::Body(i), Column::Body(k)) => {
+                                let text = RichText::new(format_value(experiment.values[i][k]));
+                                cell.label(if experiment.rule[k].contains(&i) {
+                                    text.strong().color(OPTIMAL_COLOR)
+                                } else {
+                                    text
+                                });
+                            }
+                            (Row::Footer, Column::Label) => {
+                                cell.label("Decision");
+                            }
+                            (Row::Footer, Column::Body(k)) => {
+                                let decision = experiment.rule[k]
+                                    .iter()
+                                    .map(|i| format!("A{i}"))
+                                    .collect::<Vec<String>>()
+                                    .join(", ");
+                                cell.label(decision.as_str()).on_hover_text(decision);
+                            }
+                        });
+                }
+            });
+        });
+    }
+
+    fn name(&self) -> &str {
+        "Lab 6"
+    }
+}
+
+impl Default for SixthLab {
+    fn default() -> Self {
+        #[allow(const_item_mutation)]
+        let min_len = LENGTH_RANGE.next().unwrap();
+        let length = (min_len, min_len);
+        let mut matrix = Game {
+            iterations: 20,
+            value_range: Game::VALUE_RANGE,
+            seed: random_seed(),
+            ..Default::default()
+        };
+        matrix.resize(length);
+
+        Self {
+            game: matrix,
+            length,
+            mode: Mode::default(),
+            brown_robinson_viewer: false,
+            graphical_viewer: false,
+            statistics_viewer: false,
+            statistics: Statistics::default(),
+            evaluator_viewer: false,
+            evaluator_player: Player::A,
+            evaluator_buffer: String::new(),
+            sensitivity_viewer: false,
+            sensitivity_cell: (0, 0),
+            replicator_viewer: false,
+            replicator_buffer: String::new(),
+            replicator_steps: 1000,
+            replicator_step: 0.01,
+            tree_viewer: false,
+            tree: Tree::default(),
+            tree_node: 0,
+            tree_result: None,
+            paste_viewer: false,
+            paste_buffer: String::new(),
+            paste_error: None,
+            library_viewer: false,
+            preset: Preset::default(),
+            preset_parameters: Vec::new(),
+            report_viewer: false,
+            report_format: ReportFormat::default(),
+            fractions: true,
+            experiment: false,
+            upload: file::Upload::default(),
+            file_error: None,
+        }
+    }
+}
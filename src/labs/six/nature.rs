@@ -0,0 +1,403 @@
+use std::ops::RangeInclusive;
+
+use super::StoreType;
+
+// Tolerance used when looking for equally good strategies
+const EPS: f64 = 1e-9;
+
+pub const OBSERVATIONS_RANGE: RangeInclusive<usize> = 2..=10;
+
+// Likelihood of the observation pointing to the true state in the default experiment
+const DEFAULT_ACCURACY: f64 = 0.7;
+
+#[derive(Clone, Copy, Debug, PartialEq)]
+pub enum CriterionKind {
+    Wald,
+    Maximax,
+    Savage,
+    Hurwicz,
+    Laplace,
+    Bayes,
+}
+
+impl CriterionKind {
+    pub const ALL: [CriterionKind; 6] = [
+        CriterionKind::Wald,
+        CriterionKind::Maximax,
+        CriterionKind::Savage,
+        CriterionKind::Hurwicz,
+        CriterionKind::Laplace,
+        CriterionKind::Bayes,
+    ];
+
+    pub fn as_str(&self) -> &str {
+        match self {
+            CriterionKind::Wald => "Wald",
+            CriterionKind::Maximax => "Maximax",
+            CriterionKind::Savage => "Savage",
+            CriterionKind::Hurwicz => "Hurwicz",
+            CriterionKind::Laplace => "Laplace",
+            CriterionKind::Bayes => "Bayes",
+        }
+    }
+}
+
+// Criterion value of each strategy and the optimal ones
+#[derive(Clone, Debug)]
+pub struct Criterion {
+    pub kind: CriterionKind,
+    pub values: Vec<f64>,
+    pub optimal: Vec<usize>,
+}
+
+// Decision making against nature, columns of the matrix are states of nature
+#[derive(Clone, Debug)]
+pub struct Nature {
+    // Parameters
+    pub loss: bool, // Whether the matrix contains losses instead of payoffs
+    pub alpha: f64, // Hurwicz pessimism coefficient
+    pub probabilities: Vec<f64>, // Weights of states for the Bayes criterion
+
+    pub experiment: Experiment,
+
+    // Calculation result
+    pub regret: Vec<Vec<f64>>,
+    pub criteria: Vec<Criterion>,
+}
+
+// Paid experiment observing a signal of the state before the decision is made,
+// the prior probabilities of the states are the Bayes criterion weights
+#[derive(Clone, Debug)]
+pub struct Experiment {
+    // Parameters
+    pub observations: usize,
+    pub likelihood: Vec<Vec<f64>>, // P(Ok | Bj), columns are normalized
+    pub cost: f64,
+
+    // Calculation result
+    pub evidence: Vec<f64>,        // P(Ok)
+    pub posteriors: Vec<Vec<f64>>, // P(Bj | Ok)
+    pub values: Vec<Vec<f64>>,     // Expected payoff of Ai after Ok, by strategy and observation
+    pub rule: Vec<Vec<usize>>,     // Optimal strategies after every observation
+    pub prior_value: f64,          // Expected payoff of the best decision without the experiment
+    pub sample_value: f64,         // Expected payoff of the optimal decision rule
+    pub perfect_value: f64,        // Expected payoff if the state is always known
+    pub evsi: f64,                 // Expected value of sample information
+    pub evpi: f64,                 // Expected value of perfect information
+    pub unnormalized: bool,        // Whether some likelihood column doesn't sum to 1
+}
+
+impl Nature {
+    pub fn calc(&mut self, matrix: &[Vec<StoreType>]) {
+        let columns = matrix.first().map_or(0, Vec::len);
+        if self.probabilities.len() != columns {
+            self.probabilities = vec![1.0 / columns as f64; columns];
+        }
+
+        let matrix = matrix
+            .iter()
+            .map(|row| row.iter().map(|val| val.to_f64()).collect())
+            .collect::<Vec<Vec<f64>>>();
+
+        // Losses are minimized, so the best value of the state is the lowest one
+        let loss = self.loss;
+        let best = |x: f64, y: f64| if loss { x.min(y) } else { x.max(y) };
+        let worst = |x: f64, y: f64| if loss { x.max(y) } else { x.min(y) };
+
+        self.regret = {
+            let best_in_state = (0..columns)
+                .map(|j| {
+                    matrix
+                        .iter()
+                        .map(|row| row[j])
+                        .reduce(best)
+                        .unwrap_or_default()
+                })
+                .collect::<Vec<f64>>();
+            matrix
+                .iter()
+                .map(|row| {
+                    row.iter()
+                        .zip(best_in_state.iter())
+                        .map(|(val, best)| (val - best).abs())
+                        .collect()
+                })
+                .collect()
+        };
+
+        let total = self.probabilities.iter().sum::<f64>();
+        let priors = self
+            .probabilities
+            .iter()
+            .map(|p| {
+                if total > 0.0 {
+                    p / total
+                } else {
+                    1.0 / columns as f64
+                }
+            })
+            .collect::<Vec<f64>>();
+        self.experiment.calc(&matrix, &priors, loss);
+
+        self.criteria = CriterionKind::ALL
+            .iter()
+            .map(|&kind| {
+                let values = matrix
+                    .iter()
+                    .zip(self.regret.iter())
+                    .map(|(row, regret)| {
+                        let best = row.iter().copied().reduce(best).unwrap_or_default();
+                        let worst = row.iter().copied().reduce(worst).unwrap_or_default();
+                        match kind {
+                            CriterionKind::Wald => worst,
+                            CriterionKind::Maximax => best,
+                            CriterionKind::Savage => regret.iter().copied().fold(0.0, f64::max),
+                            CriterionKind::Hurwicz => {
+                                self.alpha * worst + (1.0 - self.alpha) * best
+                            }
+                            CriterionKind::Laplace => row.iter().sum::<f64>() / columns as f64,
+                            CriterionKind::Bayes => {
+                                row.iter().zip(priors.iter()).map(|(val, p)| val * p).sum()
+                            }
+                        }
+                    })
+                    .collect::<Vec<f64>>();
+
+                // Regret is always minimized, other criteria follow the matrix meaning
+                let minimize = kind == CriterionKind::Savage || loss;
+                let optimum = values
+                    .iter()
+                    .copied()
+                    .filter(|val| val.is_finite())
+                    .reduce(if minimize { f64::min } else { f64::max });
+                let optimal = match optimum {
+                    Some(optimum) => values
+                        .iter()
+                        .enumerate()
+                        .filter(|(_, val)| (*val - optimum).abs() < EPS)
+                        .map(|(i, _)| i)
+                        .collect(),
+                    None => Vec::new(),
+                };
+
+                Criterion {
+                    kind,
+                    values,
+                    optimal,
+                }
+            })
+            .collect();
+    }
+}
+
+impl Default for Nature {
+    fn default() -> Self {
+        Self {
+            loss: false,
+            alpha: 0.5,
+            probabilities: Vec::new(),
+            experiment: Experiment::default(),
+            regret: Vec::new(),
+            criteria: Vec::new(),
+        }
+    }
+}
+
+impl Experiment {
+    // Finds the optimal decision after every observation by the Bayes rule:
+    //   P(Bj | Ok) = P(Ok | Bj) * P(Bj) / P(Ok), P(Ok) = sum(P(Ok | Bj) * P(Bj))
+    // EVSI compares it with the decision made on priors, EVPI with the best strategy in each state
+    pub fn calc(&mut self, matrix: &[Vec<f64>], priors: &[f64], loss: bool) {
+        let columns = priors.len();
+        if self.likelihood.len() != self.observations
+            || self.likelihood.iter().any(|row| row.len() != columns)
+        {
+            self.reset(columns);
+        }
+
+        // Columns not summing to 1 are normalized, zero ones give no information
+        let sums = (0..columns)
+            .map(|j| self.likelihood.iter().map(|row| row[j]).sum::<f64>())
+            .collect::<Vec<f64>>();
+        self.unnormalized = sums.iter().any(|sum| (sum - 1.0).abs() > EPS);
+        let likelihood = |k: usize, j: usize| {
+            if sums[j] > 0.0 {
+                self.likelihood[k][j] / sums[j]
+            } else {
+                1.0 / self.observations as f64
+            }
+        };
+
+        let better = |x: f64, y: f64| if loss { x < y } else { x > y };
+        let optimum = |values: &[f64]| {
+            values
+                .iter()
+                .copied()
+                .reduce(|x, y| if better(y, x) { y } else { x })
+                .unwrap_or_default()
+        };
+        let expected = |weights: &[f64]| {
+            matrix
+                .iter()
+                .map(|row| row.iter().zip(weights.iter()).map(|(a, p)| a * p).sum())
+                .collect::<Vec<f64>>()
+        };
+
+        self.evidence = (0..self.observations)
+            .map(|k| (0..columns).map(|j| likelihood(k, j) * priors[j]).sum())
+            .collect();
+        self.posteriors = (0..self.observations)
+            .map(|k| {
+                (0..columns)
+                    .map(|j| {
+                        if self.evidence[k] > 0.0 {
+                            likelihood(k, j) * priors[j] / self.evidence[k]
+                        } else {
+                            priors[j]
+                        }
+                    })
+                    .collect()
+            })
+            .collect();
+
+        let values = self
+            .posteriors
+            .iter()
+            .map(|posteriors| expected(posteriors))
+            .collect::<Vec<Vec<f64>>>();
+        self.rule = values
+            .iter()
+            .map(|values| {
+                let optimum = optimum(values);
+                (0..values.len())
+                    .filter(|&i| (values[i] - optimum).abs() < EPS)
+                    .collect()
+            })
+            .collect();
+        self.sample_value = values
+            .iter()
+            .zip(self.evidence.iter())
+            .map(|(values, p)| optimum(values) * p)
+            .sum();
+        self.values = (0..matrix.len())
+            .map(|i| values.iter().map(|values| values[i]).collect())
+            .collect();
+
+        self.prior_value = optimum(&expected(priors));
+        self.perfect_value = (0..columns)
+            .map(|j| {
+                let state = matrix.iter().map(|row| row[j]).collect::<Vec<f64>>();
+                optimum(&state) * priors[j]
+            })
+            .sum();
+
+        // Information never makes the decision worse, so both values are non-negative
+        self.evsi = (self.sample_value - self.prior_value).abs();
+        self.evpi = (self.perfect_value - self.prior_value).abs();
+    }
+
+    // Default experiment observes the state itself with the given accuracy,
+    // observations beyond the states count are never made
+    fn reset(&mut self, columns: usize) {
+        let observations = self.observations;
+        self.likelihood = (0..observations)
+            .map(|k| {
+                (0..columns)
+                    .map(|j| match j % observations {
+                        target if target == k => DEFAULT_ACCURACY,
+                        _ => (1.0 - DEFAULT_ACCURACY) / (observations - 1) as f64,
+                    })
+                    .collect()
+            })
+            .collect();
+    }
+
+    // Gain of the experiment after paying for it
+    pub fn net_gain(&self) -> f64 {
+        self.evsi - self.cost
+    }
+}
+
+impl Default for Experiment {
+    fn default() -> Self {
+        Self {
+            observations: *OBSERVATIONS_RANGE.start(),
+            likelihood: Vec::new(),
+            cost: 0.0,
+            evidence: Vec::new(),
+            posteriors: Vec::new(),
+            values: Vec::new(),
+            rule: Vec::new(),
+            prior_value: 0.0,
+            sample_value: 0.0,
+            perfect_value: 0.0,
+            evsi: 0.0,
+            evpi: 0.0,
+            unnormalized: false,
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::{super::integers, *};
+
+    fn close(x: f64, y: f64) -> bool {
+        (x - y).abs() < 1e-9
+    }
+
+    #[test]
+    fn applies_every_criterion() {
+        let mut nature = Nature::default();
+        nature.calc(&integers(&[&[100, -50], &[0, 0]]));
+        assert_eq!(nature.regret, [vec![0.0, 50.0], vec![100.0, 0.0]]);
+        let optimal = nature
+            .criteria
+            .iter()
+            .map(|criterion| (criterion.kind, criterion.optimal.clone()))
+            .collect::<Vec<_>>();
+        assert_eq!(
+            optimal,
+            [
+                (CriterionKind::Wald, vec![1]),
+                (CriterionKind::Maximax, vec![0]),
+                (CriterionKind::Savage, vec![0]),
+                (CriterionKind::Hurwicz, vec![0]),
+                (CriterionKind::Laplace, vec![0]),
+                (CriterionKind::Bayes, vec![0]),
+            ]
+        );
+    }
+
+    #[test]
+    fn values_the_experiment() {
+        let mut nature = Nature {
+            probabilities: vec![0.5, 0.5],
+            ..Default::default()
+        };
+        nature.calc(&integers(&[&[100, -50], &[0, 0]]));
+        let experiment = &nature.experiment;
+        assert!(close(experiment.prior_value, 25.0));
+        assert!(close(experiment.sample_value, 27.5));
+        assert!(close(experiment.perfect_value, 50.0));
+        assert!(close(experiment.evsi, 2.5));
+        assert!(close(experiment.evpi, 25.0));
+        assert_eq!(experiment.rule, [vec![0], vec![1]]);
+    }
+
+    #[test]
+    fn weighs_states_equally_without_probabilities() {
+        let mut nature = Nature {
+            probabilities: vec![0.0, 0.0],
+            ..Default::default()
+        };
+        nature.calc(&integers(&[&[100, -50], &[0, 0]]));
+        let bayes = nature
+            .criteria
+            .iter()
+            .find(|criterion| criterion.kind == CriterionKind::Bayes)
+            .unwrap();
+        assert!(close(bayes.values[0], 25.0) && close(bayes.values[1], 0.0));
+        assert_eq!(bayes.optimal, [0]);
+    }
+}
@@ -0,0 +1,254 @@
+use std::{cmp::Ordering, fmt};
+
+use rand::Rng;
+
+use super::Rational;
+
+// Tolerance of float comparisons in the solvers
+const EPS: f64 = 1e-9;
+
+// Numbers the solvers run on. Floats compare with a tolerance, fractions are exact
+// and their operations fail on overflow
+pub trait Field: Copy + PartialOrd {
+    fn zero() -> Self;
+    fn one() -> Self;
+    fn from_number(number: Number) -> Option<Self>;
+    fn checked_add(self, other: Self) -> Option<Self>;
+    fn checked_sub(self, other: Self) -> Option<Self>;
+    fn checked_mul(self, other: Self) -> Option<Self>;
+    fn checked_div(self, other: Self) -> Option<Self>;
+    fn is_positive(self) -> bool;
+    fn is_negative(self) -> bool;
+}
+
+impl Field for f64 {
+    fn zero() -> Self {
+        0.0
+    }
+
+    fn one() -> Self {
+        1.0
+    }
+
+    fn from_number(number: Number) -> Option<Self> {
+        Some(number.to_f64())
+    }
+
+    fn checked_add(self, other: Self) -> Option<Self> {
+        Some(self + other).filter(|val| val.is_finite())
+    }
+
+    fn checked_sub(self, other: Self) -> Option<Self> {
+        Some(self - other).filter(|val| val.is_finite())
+    }
+
+    fn checked_mul(self, other: Self) -> Option<Self> {
+        Some(self * other).filter(|val| val.is_finite())
+    }
+
+    fn checked_div(self, other: Self) -> Option<Self> {
+        Some(self / other).filter(|val| val.is_finite())
+    }
+
+    fn is_positive(self) -> bool {
+        self > EPS
+    }
+
+    fn is_negative(self) -> bool {
+        self < -EPS
+    }
+}
+
+impl Field for Rational {
+    fn zero() -> Self {
+        Self::integer(0)
+    }
+
+    fn one() -> Self {
+        Self::integer(1)
+    }
+
+    fn from_number(number: Number) -> Option<Self> {
+        number.to_rational()
+    }
+
+    fn checked_add(self, other: Self) -> Option<Self> {
+        Rational::checked_add(self, other)
+    }
+
+    fn checked_sub(self, other: Self) -> Option<Self> {
+        Rational::checked_sub(self, other)
+    }
+
+    fn checked_mul(self, other: Self) -> Option<Self> {
+        Rational::checked_mul(self, other)
+    }
+
+    fn checked_div(self, other: Self) -> Option<Self> {
+        Rational::checked_div(self, other)
+    }
+
+    fn is_positive(self) -> bool {
+        self.numer() > 0
+    }
+
+    fn is_negative(self) -> bool {
+        self.numer() < 0
+    }
+}
+
+// -------------------------------------------------------------------------------------------------
+
+#[derive(Clone, Copy, Debug, Default, PartialEq)]
+pub enum NumberKind {
+    #[default]
+    Integer,
+    Float,
+    Rational,
+}
+
+impl NumberKind {
+    pub const ALL: [NumberKind; 3] = [NumberKind::Integer, NumberKind::Float, NumberKind::Rational];
+
+    pub fn as_str(&self) -> &str {
+        match self {
+            NumberKind::Integer => "Integer",
+            NumberKind::Float => "Float",
+            NumberKind::Rational => "Rational",
+        }
+    }
+}
+
+// Payoff value, all values of a single matrix share the same kind
+#[derive(Clone, Copy, Debug)]
+pub enum Number {
+    Integer(i64),
+    Float(f64),
+    Rational(Rational),
+}
+
+impl Number {
+    // Largest denominator of randomly generated fractions
+    const RANDOM_DENOMINATOR: i64 = 6;
+
+    pub fn zero(kind: NumberKind) -> Self {
+        Self::Integer(0).convert(kind)
+    }
+
+    pub fn to_f64(self) -> f64 {
+        match self {
+            Number::Integer(val) => val as f64,
+            Number::Float(val) => val,
+            Number::Rational(val) => val.to_f64(),
+        }
+    }
+
+    // Exact value if there is one
+    pub fn to_rational(self) -> Option<Rational> {
+        match self {
+            Number::Integer(val) => Some(Rational::integer(val)),
+            Number::Float(_) => None,
+            Number::Rational(val) => Some(val),
+        }
+    }
+
+    // Converts value to another kind, integers are rounded
+    pub fn convert(self, kind: NumberKind) -> Self {
+        match (self, kind) {
+            (Number::Integer(_), NumberKind::Integer)
+            | (Number::Float(_), NumberKind::Float)
+            | (Number::Rational(_), NumberKind::Rational) => self,
+            (Number::Integer(val), NumberKind::Rational) => {
+                Number::Rational(Rational::integer(val))
+            }
+            (Number::Float(val), NumberKind::Rational) => Number::Rational(Rational::from_f64(val)),
+            (_, NumberKind::Integer) => Number::Integer(self.to_f64().round() as i64),
+            (_, NumberKind::Float) => Number::Float(self.to_f64()),
+        }
+    }
+
+    // Adds an integer to the value keeping its kind, None on overflow
+    pub fn shift(self, delta: i64) -> Option<Self> {
+        match self {
+            Number::Integer(val) => val.checked_add(delta).map(Number::Integer),
+            Number::Float(val) => Some(Number::Float(val + delta as f64)),
+            Number::Rational(val) => val
+                .checked_add(Rational::integer(delta))
+                .map(Number::Rational),
+        }
+    }
+
+    pub fn parse(text: &str, kind: NumberKind) -> Result<Self, String> {
+        match kind {
+            NumberKind::Integer => text
+                .parse::<i64>()
+                .map(Number::Integer)
+                .map_err(|_| format!("Invalid integer \"{text}\"")),
+            NumberKind::Float => match text.parse::<f64>() {
+                Ok(val) if val.is_finite() => Ok(Number::Float(val)),
+                _ => Err(format!("Invalid float \"{text}\"")),
+            },
+            NumberKind::Rational => text.parse::<Rational>().map(Number::Rational),
+        }
+    }
+
+    // Random value in range, floats are rounded to hundredths
+    pub fn random(rng: &mut impl Rng, kind: NumberKind, range: (i64, i64)) -> Self {
+        match kind {
+            NumberKind::Integer => Number::Integer(rng.gen_range(range.0..=range.1)),
+            NumberKind::Float => {
+                let val = rng.gen_range(range.0 as f64..=range.1 as f64);
+                Number::Float((val * 100.0).round() / 100.0)
+            }
+            NumberKind::Rational => {
+                let den = rng.gen_range(1..=Self::RANDOM_DENOMINATOR);
+                Number::Rational(Rational::new(
+                    rng.gen_range(range.0 * den..=range.1 * den),
+                    den,
+                ))
+            }
+        }
+    }
+}
+
+impl Default for Number {
+    fn default() -> Self {
+        Number::Integer(0)
+    }
+}
+
+impl Ord for Number {
+    fn cmp(&self, other: &Self) -> Ordering {
+        match (self.to_rational(), other.to_rational()) {
+            (Some(a), Some(b)) => a.cmp(&b),
+            _ => {
+                let (a, b) = (self.to_f64(), other.to_f64());
+                a.partial_cmp(&b).unwrap_or_else(|| a.total_cmp(&b))
+            }
+        }
+    }
+}
+
+impl PartialOrd for Number {
+    fn partial_cmp(&self, other: &Self) -> Option<Ordering> {
+        Some(self.cmp(other))
+    }
+}
+
+impl PartialEq for Number {
+    fn eq(&self, other: &Self) -> bool {
+        self.cmp(other) == Ordering::Equal
+    }
+}
+
+impl Eq for Number {}
+
+impl fmt::Display for Number {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            Number::Integer(val) => write!(f, "{val}"),
+            Number::Float(val) => write!(f, "{val}"),
+            Number::Rational(val) => write!(f, "{val}"),
+        }
+    }
+}
@@ -0,0 +1,289 @@
+use std::{cmp::Ordering, ops::RangeInclusive};
+
+use super::{Game, Rational};
+
+// Battlefields of Colonel Blotto, strategies grow quadratically with the troops
+const BATTLEFIELDS: usize = 3;
+
+// Well-known zero-sum games, payoffs are of the row player A
+#[derive(Clone, Copy, Debug, Default, PartialEq)]
+pub enum Preset {
+    #[default]
+    MatchingPennies,
+    RockPaperScissors,
+    ColonelBlotto,
+    Morra,
+    NoisyDuel,
+    SilentDuel,
+    Inspection,
+}
+
+// Size of the parametrized family of games
+pub struct Parameter {
+    pub name: &'static str,
+    pub range: RangeInclusive<usize>,
+    pub default: usize,
+}
+
+impl Preset {
+    pub const ALL: [Preset; 7] = [
+        Preset::MatchingPennies,
+        Preset::RockPaperScissors,
+        Preset::ColonelBlotto,
+        Preset::Morra,
+        Preset::NoisyDuel,
+        Preset::SilentDuel,
+        Preset::Inspection,
+    ];
+
+    pub fn as_str(&self) -> &str {
+        match self {
+            Preset::MatchingPennies => "Matching Pennies",
+            Preset::RockPaperScissors => "Rock-Paper-Scissors",
+            Preset::ColonelBlotto => "Colonel Blotto",
+            Preset::Morra => "Morra",
+            Preset::NoisyDuel => "Noisy Duel",
+            Preset::SilentDuel => "Silent Duel",
+            Preset::Inspection => "Inspection Game",
+        }
+    }
+
+    pub fn description(&self) -> &str {
+        match self {
+            Preset::MatchingPennies => {
+                "Both players show heads or tails. A wins 1 if the coins match, B wins 1 \
+                otherwise. Strategies are heads and tails, the value is 0."
+            }
+            Preset::RockPaperScissors => {
+                "Rock beats scissors, scissors beat paper, paper beats rock. Strategies are \
+                rock, paper and scissors, all of them are played with probability 1/3."
+            }
+            Preset::ColonelBlotto => {
+                "Both colonels split their troops over 3 battlefields, the side with more troops \
+                wins the battlefield. A gets the won battlefields count minus the lost one. \
+                Strategies are splits (x1, x2, x3) in lexicographic order."
+            }
+            Preset::Morra => {
+                "Both players show 1 to F fingers and guess the fingers of the opponent. If only \
+                one of them guesses right, that player wins the total fingers shown. Strategy \
+                (F * (show - 1) + guess - 1) shows and guesses the given fingers."
+            }
+            Preset::NoisyDuel => {
+                "Duelists approach each other in N steps, at step k the shot hits with \
+                probability k / N. Shots are heard, so the one who missed is shot at point \
+                blank. Strategy k fires at step k, A gets 1 if B is hit and -1 if A is hit."
+            }
+            Preset::SilentDuel => {
+                "Duelists approach each other in N steps, at step k the shot hits with \
+                probability k / N. Shots are silent, so the one who missed doesn't know it. \
+                Strategy k fires at step k, A gets 1 if B is hit and -1 if A is hit."
+            }
+            Preset::Inspection => {
+                "Inspector A checks one of N sites, violator B complies (the first strategy) \
+                or breaks the rules at a site. Violation at site k gains k to B unless it is \
+                inspected, then B pays the fine."
+            }
+        }
+    }
+
+    // Sizes of the family, empty for the fixed games
+    pub fn parameters(&self) -> Vec<Parameter> {
+        match self {
+            Preset::MatchingPennies | Preset::RockPaperScissors => Vec::new(),
+            Preset::ColonelBlotto => vec![
+                Parameter {
+                    name: "Troops of A: ",
+                    range: 1..=8,
+                    default: 4,
+                },
+                Parameter {
+                    name: "Troops of B: ",
+                    range: 1..=8,
+                    default: 3,
+                },
+            ],
+            Preset::Morra => vec![Parameter {
+                name: "Fingers: ",
+                range: 2..=5,
+                default: 3,
+            }],
+            Preset::NoisyDuel | Preset::SilentDuel => vec![Parameter {
+                name: "Steps: ",
+                range: 2..=10,
+                default: 5,
+            }],
+            Preset::Inspection => vec![
+                Parameter {
+                    name: "Sites: ",
+                    range: 2..=10,
+                    default: 3,
+                },
+                Parameter {
+                    name: "Fine: ",
+                    range: 1..=20,
+                    default: 1,
+                },
+            ],
+        }
+    }
+
+    // Payoff matrix of A for the parameters given in the order of `parameters`
+    pub fn matrix(&self, parameters: &[usize]) -> Vec<Vec<Rational>> {
+        let integers = |matrix: Vec<Vec<i64>>| {
+            matrix
+                .into_iter()
+                .map(|row| row.into_iter().map(Rational::integer).collect())
+                .collect()
+        };
+        match self {
+            Preset::MatchingPennies => integers(vec![vec![1, -1], vec![-1, 1]]),
+            Preset::RockPaperScissors => {
+                integers(vec![vec![0, -1, 1], vec![1, 0, -1], vec![-1, 1, 0]])
+            }
+            Preset::ColonelBlotto => {
+                let a = splits(parameters[0], BATTLEFIELDS);
+                let b = splits(parameters[1], BATTLEFIELDS);
+                integers(
+                    a.iter()
+                        .map(|x| {
+                            b.iter()
+                                .map(|y| x.iter().zip(y.iter()).map(|(x, y)| x.cmp(y) as i64).sum())
+                                .collect()
+                        })
+                        .collect(),
+                )
+            }
+            Preset::Morra => {
+                let fingers = parameters[0];
+                let strategies = (1..=fingers)
+                    .flat_map(|show| (1..=fingers).map(move |guess| (show, guess)))
+                    .collect::<Vec<(usize, usize)>>();
+                integers(
+                    strategies
+                        .iter()
+                        .map(|&(show_a, guess_a)| {
+                            strategies
+                                .iter()
+                                .map(|&(show_b, guess_b)| {
+                                    let total = (show_a + show_b) as i64;
+                                    match (guess_a == show_b, guess_b == show_a) {
+                                        (true, false) => total,
+                                        (false, true) => -total,
+                                        _ => 0,
+                                    }
+                                })
+                                .collect()
+                        })
+                        .collect(),
+                )
+            }
+            Preset::NoisyDuel | Preset::SilentDuel => {
+                let n = parameters[0] as i64;
+                let noisy = *self == Preset::NoisyDuel;
+                // Payoff of the first shooter at step i against the one waiting for step j > i,
+                // the shot hits with probability i / n
+                let first = |i: i64, j: i64| {
+                    if noisy {
+                        Rational::new(2 * i - n, n)
+                    } else {
+                        Rational::new(i * n - (n - i) * j, n * n)
+                    }
+                };
+                (0..=n)
+                    .map(|i| {
+                        (0..=n)
+                            .map(|j| match i.cmp(&j) {
+                                Ordering::Less => first(i, j),
+                                Ordering::Greater => {
+                                    Rational::integer(0).checked_sub(first(j, i)).unwrap()
+                                }
+                                Ordering::Equal => Rational::integer(0),
+                            })
+                            .collect()
+                    })
+                    .collect()
+            }
+            Preset::Inspection => {
+                let (sites, fine) = (parameters[0], parameters[1] as i64);
+                integers(
+                    (1..=sites)
+                        .map(|inspected| {
+                            (0..=sites)
+                                .map(|violated| match violated {
+                                    0 => 0,
+                                    _ if violated == inspected => fine,
+                                    _ => -(violated as i64),
+                                })
+                                .collect()
+                        })
+                        .collect(),
+                )
+            }
+        }
+    }
+}
+
+// Splits of the troops over the battlefields in lexicographic order
+fn splits(troops: usize, battlefields: usize) -> Vec<Vec<usize>> {
+    if battlefields == 1 {
+        return vec![vec![troops]];
+    }
+    (0..=troops)
+        .flat_map(|first| {
+            splits(troops - first, battlefields - 1)
+                .into_iter()
+                .map(move |rest| [vec![first], rest].concat())
+        })
+        .collect()
+}
+
+impl Game {
+    pub fn load_preset(&mut self, preset: Preset, parameters: &[usize]) {
+        let matrix = preset.matrix(parameters);
+        let opposite = matrix
+            .iter()
+            .map(|row| {
+                row.iter()
+                    .map(|val| Rational::integer(0).checked_sub(*val).unwrap())
+                    .collect()
+            })
+            .collect::<Vec<Vec<Rational>>>();
+        self.set_rationals(&matrix, &opposite);
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::{
+        super::{mixed::MixedSolution, StoreType},
+        *,
+    };
+
+    fn value(preset: Preset, parameters: &[usize]) -> Rational {
+        let matrix = preset
+            .matrix(parameters)
+            .iter()
+            .map(|row| row.iter().map(|&val| StoreType::Rational(val)).collect())
+            .collect::<Vec<Vec<StoreType>>>();
+        MixedSolution::solve(&matrix).unwrap().exact.unwrap().value
+    }
+
+    #[test]
+    fn symmetric_games_are_fair() {
+        let zero = Rational::integer(0);
+        assert_eq!(value(Preset::MatchingPennies, &[]), zero);
+        assert_eq!(value(Preset::RockPaperScissors, &[]), zero);
+        assert_eq!(value(Preset::Morra, &[3]), zero);
+        assert_eq!(value(Preset::NoisyDuel, &[5]), zero);
+        assert_eq!(value(Preset::SilentDuel, &[5]), zero);
+    }
+
+    #[test]
+    fn sizes_follow_parameters() {
+        // Splits of 4 and 3 troops over 3 battlefields
+        let blotto = Preset::ColonelBlotto.matrix(&[4, 3]);
+        assert_eq!((blotto.len(), blotto[0].len()), (15, 10));
+        let inspection = Preset::Inspection.matrix(&[3, 1]);
+        assert_eq!((inspection.len(), inspection[0].len()), (3, 4));
+    }
+}
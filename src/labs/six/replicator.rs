@@ -0,0 +1,226 @@
+use super::{
+    bimatrix::{indifference, same, subsets},
+    evaluator::Evaluation,
+    StoreType,
+};
+
+// Tolerance of the equilibrium conditions
+const EPS: f64 = 1e-9;
+
+// Initial mixes of the phase portrait are the grid points inside the simplex with this step
+const PORTRAIT_GRID: usize = 5;
+
+// Population of the symmetric game, the payoff matrix gives the payoff of strategy i against j
+#[derive(Clone, Debug, Default)]
+pub struct Replicator {
+    pub initial: Vec<f64>, // Parameters of the run
+    pub steps: usize,
+    pub step: f64,
+
+    // Result
+    pub trajectory: Vec<Vec<f64>>, // Shares of the strategies at every step
+    pub portrait: Vec<Vec<Vec<f64>>>, // Trajectories from the grid of mixes of 3 strategies
+    pub stable: Vec<Vec<f64>>,     // Evolutionarily stable strategies
+}
+
+impl Replicator {
+    pub fn run(matrix: &[Vec<StoreType>], initial: &[f64], steps: usize, step: f64) -> Self {
+        let matrix = matrix
+            .iter()
+            .map(|row| row.iter().map(|val| val.to_f64()).collect())
+            .collect::<Vec<Vec<f64>>>();
+
+        let mut portrait = Vec::new();
+        if matrix.len() == 3 {
+            (1..PORTRAIT_GRID).for_each(|i| {
+                (1..PORTRAIT_GRID - i).for_each(|j| {
+                    let k = PORTRAIT_GRID - i - j;
+                    let mix = [i, j, k].map(|count| count as f64 / PORTRAIT_GRID as f64);
+                    portrait.push(trajectory(&matrix, &mix, steps, step));
+                })
+            });
+        }
+
+        Self {
+            initial: initial.to_vec(),
+            steps,
+            step,
+            trajectory: trajectory(&matrix, initial, steps, step),
+            portrait,
+            stable: stable_strategies(&matrix),
+        }
+    }
+
+    // Parses initial shares of the strategies as probabilities, counts of the individuals
+    // are accepted too and normalized. Empty text gives the uniform mix
+    pub fn parse_initial(text: &str, length: usize) -> Result<Vec<f64>, String> {
+        if text.trim().is_empty() {
+            return Ok(vec![1.0 / length as f64; length]);
+        }
+        let weights = Evaluation::parse(text)?;
+        if weights.len() != length {
+            return Err(format!("Expected {length} shares, got {}", weights.len()));
+        }
+        if let Some(weight) = weights.iter().find(|weight| **weight < 0.0) {
+            return Err(format!("Negative share {weight}"));
+        }
+        let total = weights.iter().sum::<f64>();
+        if total <= 0.0 {
+            return Err("Shares sum to zero".to_string());
+        }
+        Ok(weights.iter().map(|weight| weight / total).collect())
+    }
+}
+
+// Payoffs of every strategy against the population
+fn fitness(matrix: &[Vec<f64>], shares: &[f64]) -> Vec<f64> {
+    matrix
+        .iter()
+        .map(|row| row.iter().zip(shares.iter()).map(|(a, x)| a * x).sum())
+        .collect()
+}
+
+// Euler steps of dx(i)/dt = x(i) * (f(i) - average f), shares are kept on the simplex
+fn trajectory(matrix: &[Vec<f64>], initial: &[f64], steps: usize, step: f64) -> Vec<Vec<f64>> {
+    let mut shares = initial.to_vec();
+    let mut trajectory = Vec::with_capacity(steps + 1);
+    trajectory.push(shares.clone());
+
+    for _ in 0..steps {
+        let fitness = fitness(matrix, &shares);
+        let average = fitness
+            .iter()
+            .zip(shares.iter())
+            .map(|(f, x)| f * x)
+            .sum::<f64>();
+        shares
+            .iter_mut()
+            .zip(fitness.iter())
+            .for_each(|(x, f)| *x = (*x + step * *x * (f - average)).max(0.0));
+        let total = shares.iter().sum::<f64>();
+        if total > 0.0 {
+            shares.iter_mut().for_each(|x| *x /= total);
+        }
+        trajectory.push(shares.clone());
+    }
+    trajectory
+}
+
+// Symmetric equilibria x are found over all supports, x is stable if y'Ay < 0 for all
+// directions y between the best responses to x, so no mutant can invade.
+// The condition is exact when the best responses are the support of x
+fn stable_strategies(matrix: &[Vec<f64>]) -> Vec<Vec<f64>> {
+    let n = matrix.len();
+    let mut stable: Vec<Vec<f64>> = Vec::new();
+
+    for size in 1..=n {
+        for support in subsets(n, size) {
+            let (weights, value) = match indifference(size, |k, l| matrix[support[k]][support[l]]) {
+                Some(solution) => solution,
+                None => continue,
+            };
+            if weights.iter().any(|p| *p < -EPS) {
+                continue;
+            }
+            let mut shares = vec![0.0; n];
+            support
+                .iter()
+                .zip(weights.iter())
+                .for_each(|(&i, p)| shares[i] = p.max(0.0));
+
+            let fitness = fitness(matrix, &shares);
+            if fitness.iter().any(|f| *f > value + EPS) {
+                continue;
+            }
+            let responses = (0..n)
+                .filter(|&i| (fitness[i] - value).abs() < EPS)
+                .collect::<Vec<usize>>();
+            if negative_definite(matrix, &responses)
+                && !stable.iter().any(|other| same(other, &shares))
+            {
+                stable.push(shares);
+            }
+        }
+    }
+    stable
+}
+
+// Whether y'Ay < 0 for all y != 0 with sum(y) = 0 and non-zero values only at the
+// given strategies, checked by the pivots of Gaussian elimination over the basis e(k) - e(first)
+fn negative_definite(matrix: &[Vec<f64>], strategies: &[usize]) -> bool {
+    let first = strategies[0];
+    let others = &strategies[1..];
+    let quadratic = |i: usize, j: usize| {
+        matrix[i][j] - matrix[i][first] - matrix[first][j] + matrix[first][first]
+    };
+    let mut form = others
+        .iter()
+        .map(|&i| {
+            others
+                .iter()
+                .map(|&j| -(quadratic(i, j) + quadratic(j, i)) / 2.0)
+                .collect::<Vec<f64>>()
+        })
+        .collect::<Vec<Vec<f64>>>();
+
+    let size = others.len();
+    for k in 0..size {
+        let pivot = form[k][k];
+        if pivot <= EPS {
+            return false;
+        }
+        let (upper, lower) = form.split_at_mut(k + 1);
+        for row in lower {
+            let factor = row[k] / pivot;
+            row.iter_mut()
+                .zip(upper[k].iter())
+                .skip(k)
+                .for_each(|(val, p)| *val -= factor * p);
+        }
+    }
+    true
+}
+
+#[cfg(test)]
+mod tests {
+    use super::{super::integers, *};
+
+    fn stable(matrix: &[&[i64]]) -> Vec<Vec<f64>> {
+        let length = matrix.len();
+        Replicator::run(
+            &integers(matrix),
+            &vec![1.0 / length as f64; length],
+            10,
+            0.01,
+        )
+        .stable
+    }
+
+    #[test]
+    fn finds_hawk_dove_mix() {
+        let stable = stable(&[&[-1, 4], &[0, 2]]);
+        assert_eq!(stable.len(), 1);
+        assert!(same(&stable[0], &[2.0 / 3.0, 1.0 / 3.0]));
+    }
+
+    #[test]
+    fn finds_no_stable_strategy_of_rock_paper_scissors() {
+        assert!(stable(&[&[0, -1, 1], &[1, 0, -1], &[-1, 1, 0]]).is_empty());
+    }
+
+    #[test]
+    fn finds_both_conventions_of_coordination() {
+        let stable = stable(&[&[2, 0], &[0, 1]]);
+        assert_eq!(stable.len(), 2);
+        assert!(stable.iter().any(|shares| same(shares, &[1.0, 0.0])));
+        assert!(stable.iter().any(|shares| same(shares, &[0.0, 1.0])));
+    }
+
+    #[test]
+    fn converges_to_hawk_dove_mix() {
+        let replicator = Replicator::run(&integers(&[&[-1, 4], &[0, 2]]), &[0.1, 0.9], 5000, 0.01);
+        let last = replicator.trajectory.last().unwrap();
+        assert!((last[0] - 2.0 / 3.0).abs() < 1e-3);
+        assert!(replicator.portrait.is_empty());
+    }
+}
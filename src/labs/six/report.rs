@@ -0,0 +1,388 @@
+use super::{format_value, mixed::MixedSolution, Game, Rational, StoreType};
+
+const TITLE: &str = "Matrix Game Solution";
+
+#[derive(Clone, Copy, Debug, Default, PartialEq)]
+pub enum ReportFormat {
+    #[default]
+    Markdown,
+    Latex,
+}
+
+impl ReportFormat {
+    pub const ALL: [ReportFormat; 2] = [ReportFormat::Markdown, ReportFormat::Latex];
+
+    pub fn as_str(&self) -> &str {
+        match self {
+            ReportFormat::Markdown => "Markdown",
+            ReportFormat::Latex => "LaTeX",
+        }
+    }
+
+    pub fn extension(&self) -> &str {
+        match self {
+            ReportFormat::Markdown => "md",
+            ReportFormat::Latex => "tex",
+        }
+    }
+}
+
+// Text of the report, math is written in LaTeX notation in both formats
+struct Report {
+    format: ReportFormat,
+    text: String,
+}
+
+impl Report {
+    fn heading(&mut self, text: &str) {
+        match self.format {
+            ReportFormat::Markdown => self.text += &format!("## {text}\n\n"),
+            ReportFormat::Latex => self.text += &format!("\\section*{{{text}}}\n\n"),
+        }
+    }
+
+    fn paragraph(&mut self, text: &str) {
+        self.text += text;
+        self.text += "\n\n";
+    }
+
+    fn list(&mut self, items: &[String]) {
+        match self.format {
+            ReportFormat::Markdown => {
+                items
+                    .iter()
+                    .for_each(|item| self.text += &format!("- {item}\n"));
+                self.text += "\n";
+            }
+            ReportFormat::Latex => {
+                self.text += "\\begin{itemize}\n";
+                items
+                    .iter()
+                    .for_each(|item| self.text += &format!("  \\item {item}\n"));
+                self.text += "\\end{itemize}\n\n";
+            }
+        }
+    }
+
+    fn table(&mut self, header: &[String], rows: &[Vec<String>]) {
+        match self.format {
+            ReportFormat::Markdown => {
+                self.text += &format!("| {} |\n", header.join(" | "));
+                self.text += &format!("|{}\n", " --- |".repeat(header.len()));
+                rows.iter()
+                    .for_each(|row| self.text += &format!("| {} |\n", row.join(" | ")));
+                self.text += "\n";
+            }
+            ReportFormat::Latex => {
+                self.text += &format!(
+                    "\\begin{{center}}\n\\begin{{tabular}}{{c|{}}}\n",
+                    "c".repeat(header.len() - 1)
+                );
+                self.text += &format!("  {} \\\\\n  \\hline\n", header.join(" & "));
+                rows.iter()
+                    .for_each(|row| self.text += &format!("  {} \\\\\n", row.join(" & ")));
+                self.text += "\\end{tabular}\n\\end{center}\n\n";
+            }
+        }
+    }
+
+    fn finish(self) -> String {
+        match self.format {
+            ReportFormat::Markdown => format!("# {TITLE}\n\n{}", self.text),
+            ReportFormat::Latex => format!(
+                "\\documentclass{{article}}\n\\usepackage{{amsmath}}\n\n\\title{{{TITLE}}}\n\\date{{}}\n\n\\begin{{document}}\n\n\\maketitle\n\n{}\\end{{document}}\n",
+                self.text
+            ),
+        }
+    }
+}
+
+// Value in the math notation, fractions are written as \frac
+fn math(value: &StoreType) -> String {
+    match value.to_rational() {
+        Some(val) => math_fraction(val),
+        None => value.to_string(),
+    }
+}
+
+fn math_fraction(val: Rational) -> String {
+    if val.denom() == 1 {
+        val.to_string()
+    } else {
+        let sign = if val.numer() < 0 { "-" } else { "" };
+        format!("{sign}\\frac{{{}}}{{{}}}", val.numer().abs(), val.denom())
+    }
+}
+
+fn math_list(values: &[StoreType]) -> String {
+    values.iter().map(math).collect::<Vec<String>>().join(", ")
+}
+
+fn probabilities(values: &[f64]) -> String {
+    values
+        .iter()
+        .map(|&p| format_value(p))
+        .collect::<Vec<String>>()
+        .join(", ")
+}
+
+// Strategies of A and B and the game value, written with the given formatting
+// of fractions if the solution is exact and they are preferred
+fn solution_texts(
+    mixed: &MixedSolution,
+    fractions: bool,
+    fraction: impl Fn(Rational) -> String,
+) -> [String; 3] {
+    match mixed.exact.as_ref().filter(|_| fractions) {
+        Some(exact) => {
+            let list = |values: &[Rational]| {
+                values
+                    .iter()
+                    .map(|&val| fraction(val))
+                    .collect::<Vec<String>>()
+                    .join(", ")
+            };
+            [list(&exact.a), list(&exact.b), fraction(exact.value)]
+        }
+        None => [
+            probabilities(&mixed.a),
+            probabilities(&mixed.b),
+            format_value(mixed.value),
+        ],
+    }
+}
+
+impl Game {
+    // Step-by-step solution of the matrix game, written again only when the game
+    // or the way it is shown changes
+    pub fn report(&mut self, format: ReportFormat, fractions: bool) -> &str {
+        let outdated = self
+            .report
+            .as_ref()
+            .is_none_or(|report| report.0 != format || report.1 != fractions);
+        if outdated {
+            let text = self.write_report(format, fractions);
+            self.report = Some((format, fractions, text));
+        }
+        &self.report.as_ref().unwrap().2
+    }
+
+    fn write_report(&mut self, format: ReportFormat, fractions: bool) -> String {
+        let mut report = Report {
+            format,
+            text: String::new(),
+        };
+
+        report.heading("Payoff matrix");
+        report.paragraph(&format!(
+            "Player $A$ has {} strategies, player $B$ has {}. \
+            Values of the matrix are payoffs of $A$ and losses of $B$.",
+            self.rows, self.columns
+        ));
+        let header = [String::new()]
+            .into_iter()
+            .chain((0..self.columns).map(|j| format!("$B_{{{j}}}$")))
+            .chain(["$\\min(A_i)$".to_string()])
+            .collect::<Vec<String>>();
+        let rows = self
+            .matrix
+            .iter()
+            .zip(self.a_min.iter())
+            .enumerate()
+            .map(|(i, (row, min))| {
+                [format!("$A_{{{i}}}$")]
+                    .into_iter()
+                    .chain(row.iter().map(|val| format!("${}$", math(val))))
+                    .chain([format!("${}$", math(min))])
+                    .collect()
+            })
+            .chain([["$\\max(B_j)$".to_string()]
+                .into_iter()
+                .chain(self.b_max.iter().map(|val| format!("${}$", math(val))))
+                .chain([String::new()])
+                .collect()])
+            .collect::<Vec<Vec<String>>>();
+        report.table(&header, &rows);
+
+        report.heading("Lower and upper values");
+        report.paragraph(&format!(
+            "Lower value of the game: $\\alpha = \\max_i \\min_j a_{{ij}} = \\max({}) = {}$.",
+            math_list(&self.a_min),
+            math(&self.max_min)
+        ));
+        report.paragraph(&format!(
+            "Upper value of the game: $\\beta = \\min_j \\max_i a_{{ij}} = \\min({}) = {}$.",
+            math_list(&self.b_max),
+            math(&self.min_max)
+        ));
+
+        let reduction = self.reduction().clone();
+        if !reduction.steps.is_empty() {
+            report.heading("Dominated strategies");
+            report.list(
+                &reduction
+                    .steps
+                    .iter()
+                    .map(|step| {
+                        let player = step.player.as_str();
+                        format!(
+                            "${player}_{{{}}}$ is {} dominated by ${player}_{{{}}}$",
+                            step.removed,
+                            if step.strict { "strictly" } else { "weakly" },
+                            step.by
+                        )
+                    })
+                    .collect::<Vec<String>>(),
+            );
+            report.paragraph("Reduced matrix:");
+            let header = [String::new()]
+                .into_iter()
+                .chain(reduction.columns.iter().map(|j| format!("$B_{{{j}}}$")))
+                .collect::<Vec<String>>();
+            let rows = reduction
+                .matrix
+                .iter()
+                .zip(reduction.rows.iter())
+                .map(|(row, i)| {
+                    [format!("$A_{{{i}}}$")]
+                        .into_iter()
+                        .chain(row.iter().map(|val| format!("${}$", math(val))))
+                        .collect()
+                })
+                .collect::<Vec<Vec<String>>>();
+            report.table(&header, &rows);
+        }
+
+        report.heading("Solution");
+        match self.saddle_points.as_slice() {
+            [] => {
+                report.paragraph(
+                    "Since $\\alpha < \\beta$, the game has no saddle point \
+                    and $\\alpha \\le V \\le \\beta$, so it is solved in mixed strategies.",
+                );
+                match self.mixed() {
+                    Some(mixed) => {
+                        let [p, q, value] = solution_texts(mixed, fractions, math_fraction);
+                        report.list(&[
+                            format!("Optimal strategy of $A$: $P = ({p})$"),
+                            format!("Optimal strategy of $B$: $Q = ({q})$"),
+                            format!("Game value: $V = {value}$"),
+                        ]);
+                    }
+                    None => report.paragraph("The mixed strategy solution wasn't found."),
+                }
+            }
+            points => {
+                report.paragraph(&format!(
+                    "Since $\\alpha = \\beta = {}$, the game has a solution in pure strategies.",
+                    math(&self.min_max)
+                ));
+                report.list(&[
+                    format!(
+                        "Saddle point{}: {}",
+                        if points.len() > 1 { "s" } else { "" },
+                        points
+                            .iter()
+                            .map(|(i, j)| format!("$(A_{{{i}}}, B_{{{j}}})$"))
+                            .collect::<Vec<String>>()
+                            .join(", ")
+                    ),
+                    format!("Game value: $V = {}$", math(&self.min_max)),
+                ]);
+            }
+        }
+
+        report.finish()
+    }
+
+    // Plain text results, the same as shown in the side panel
+    pub fn summary(&mut self, fractions: bool) -> String {
+        let mut lines = vec![
+            format!(
+                "Matrix: {}x{} ({})",
+                self.rows,
+                self.columns,
+                self.kind.as_str()
+            ),
+            format!("max(min(Ai)) = {}", self.max_min),
+            format!("min(max(Bj)) = {}", self.min_max),
+        ];
+        match self.saddle_points.as_slice() {
+            [] => {
+                lines.push("Saddle points: none".to_string());
+                if let Some(mixed) = self.mixed() {
+                    let [p, q, value] = solution_texts(mixed, fractions, |val| val.to_string());
+                    lines.push(format!("V = {value}"));
+                    lines.push(format!("P = ({p})"));
+                    lines.push(format!("Q = ({q})"));
+                }
+            }
+            points => {
+                lines.push(format!(
+                    "Saddle points: {}",
+                    points
+                        .iter()
+                        .map(|point| format!("{point:?}"))
+                        .collect::<Vec<String>>()
+                        .join(", ")
+                ));
+                lines.push(format!("V = {}", self.min_max));
+            }
+        }
+        lines.join("\n")
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::{super::integers, *};
+
+    fn game() -> Game {
+        let mut game = Game::default();
+        game.set_matrix(integers(&[&[2, 6, 4], &[0, 0, 5], &[4, -4, 4]]));
+        game
+    }
+
+    #[test]
+    fn summarizes_the_solution() {
+        let summary = game().summary(true);
+        assert!(summary.contains("V = 8/3"));
+        assert!(summary.contains("P = (2/3, 0, 1/3)"));
+        assert!(summary.contains("Q = (5/6, 1/6, 0)"));
+
+        let summary = game().summary(false);
+        assert!(summary.contains("V = 2.6667"));
+        assert!(summary.contains("P = (0.6667, 0, 0.3333)"));
+        assert!(summary.contains("Q = (0.8333, 0.1667, 0)"));
+    }
+
+    #[test]
+    fn reports_the_solution_in_both_formats() {
+        ReportFormat::ALL.into_iter().for_each(|format| {
+            let mut game = game();
+            let report = game.report(format, true);
+            assert!(report.contains("$P = (\\frac{2}{3}, 0, \\frac{1}{3})$"));
+            assert!(report.contains("$Q = (\\frac{5}{6}, \\frac{1}{6}, 0)$"));
+            assert!(report.contains("$V = \\frac{8}{3}$"));
+            assert_eq!(
+                report.starts_with("\\documentclass"),
+                format == ReportFormat::Latex
+            );
+        });
+
+        let mut game = game();
+        let report = game.report(ReportFormat::Markdown, false);
+        assert!(report.contains("$P = (0.6667, 0, 0.3333)$"));
+        assert!(report.contains("$V = 2.6667$"));
+    }
+
+    #[test]
+    fn reports_saddle_points() {
+        let mut game = Game::default();
+        game.set_matrix(integers(&[&[3, 5], &[1, 2]]));
+        assert!(game.summary(true).contains("Saddle points: (0, 0)"));
+        let report = game.report(ReportFormat::Markdown, true);
+        assert!(report.contains("Saddle point: $(A_{0}, B_{0})$"));
+        assert!(report.contains("$V = 3$"));
+    }
+}
@@ -0,0 +1,190 @@
+use super::{mixed::MixedSolution, StoreType, VALUE_LIMIT};
+
+// Probability of the strategy counted as played
+const EPS: f64 = 1e-7;
+
+// Bounds are found by bisection up to this precision
+const PRECISION: f64 = 1e-6;
+
+// First change of the payoff tried when looking for a bound, doubled every time
+const FIRST_STEP: f64 = 0.25;
+
+// Points of the game value curve
+const CURVE_POINTS: usize = 100;
+
+// Strategies of A and B played with non-zero probability
+type Supports = (Vec<usize>, Vec<usize>);
+
+// How the solution of the game depends on every single payoff, the optimal strategies
+// are considered unchanged while they are played with the same supports
+#[derive(Clone, Debug, Default)]
+pub struct Sensitivity {
+    pub bounds: Vec<Vec<(Option<f64>, Option<f64>)>>, // Payoff ranges keeping the supports, None if unbounded
+    pub critical: Vec<Vec<f64>>, // Closeness of the nearest bound, 1 at the bound and 0 far away
+    pub cell: (usize, usize),    // Cell of the value curve
+    pub curve: Vec<(f64, f64)>,  // Game value by the payoff of the cell
+}
+
+impl Sensitivity {
+    pub fn analyse(matrix: &[Vec<StoreType>]) -> Self {
+        let matrix = matrix
+            .iter()
+            .map(|row| {
+                row.iter()
+                    .map(|val| StoreType::Float(val.to_f64()))
+                    .collect()
+            })
+            .collect::<Vec<Vec<StoreType>>>();
+        let supports = supports(&matrix);
+        let span = span(&matrix);
+
+        let mut sensitivity = Self::default();
+        matrix.iter().enumerate().for_each(|(i, row)| {
+            let mut bounds = Vec::with_capacity(row.len());
+            let mut critical = Vec::with_capacity(row.len());
+            row.iter().enumerate().for_each(|(j, val)| {
+                let payoff = val.to_f64();
+                let start = payoff.min(*VALUE_LIMIT.start() as f64);
+                let end = payoff.max(*VALUE_LIMIT.end() as f64);
+                let low = bound(&matrix, (i, j), &supports, start);
+                let high = bound(&matrix, (i, j), &supports, end);
+                let distance = low
+                    .map_or(f64::INFINITY, |low| payoff - low)
+                    .min(high.map_or(f64::INFINITY, |high| high - payoff));
+                bounds.push((low, high));
+                critical.push(1.0 - (distance / span).min(1.0));
+            });
+            sensitivity.bounds.push(bounds);
+            sensitivity.critical.push(critical);
+        });
+        sensitivity.select(&matrix, (0, 0));
+        sensitivity
+    }
+
+    // Computes the game value curve of the cell if it isn't computed yet. The payoff goes
+    // over the spread of the matrix around its value and both bounds when they are close
+    pub fn select(&mut self, matrix: &[Vec<StoreType>], cell: (usize, usize)) {
+        if self.cell == cell && !self.curve.is_empty() {
+            return;
+        }
+        let (i, j) = cell;
+        let payoff = matrix[i][j].to_f64();
+        let span = span(matrix);
+        let (low, high) = self.bounds[i][j];
+
+        let start = (payoff - span)
+            .min(low.map_or(f64::INFINITY, |low| low - span / 4.0))
+            .max(*VALUE_LIMIT.start() as f64);
+        let end = (payoff + span)
+            .max(high.map_or(f64::NEG_INFINITY, |high| high + span / 4.0))
+            .min(*VALUE_LIMIT.end() as f64);
+
+        let mut changed = matrix.to_vec();
+        self.cell = cell;
+        self.curve = (0..=CURVE_POINTS)
+            .filter_map(|k| {
+                let x = start + (end - start) * k as f64 / CURVE_POINTS as f64;
+                changed[i][j] = StoreType::Float(x);
+                MixedSolution::solve(&changed).map(|solution| (x, solution.value))
+            })
+            .collect();
+    }
+}
+
+// Spread of the payoffs, distances to the bounds are compared with it
+fn span(matrix: &[Vec<StoreType>]) -> f64 {
+    let payoffs = matrix.iter().flatten().map(|val| val.to_f64());
+    let (min, max) = payoffs.fold((f64::INFINITY, f64::NEG_INFINITY), |(min, max), val| {
+        (min.min(val), max.max(val))
+    });
+    (max - min).max(1.0)
+}
+
+fn supports(matrix: &[Vec<StoreType>]) -> Option<Supports> {
+    let solution = MixedSolution::solve(matrix)?;
+    let played = |probabilities: &[f64]| {
+        (0..probabilities.len())
+            .filter(|&k| probabilities[k] > EPS)
+            .collect()
+    };
+    Some((played(&solution.a), played(&solution.b)))
+}
+
+// Furthest payoff of the cell towards the limit at which the supports are kept, None if they
+// are kept all the way. The change is doubled until the supports differ, then bisected
+fn bound(
+    matrix: &[Vec<StoreType>],
+    (i, j): (usize, usize),
+    initial: &Option<Supports>,
+    limit: f64,
+) -> Option<f64> {
+    let mut changed = matrix.to_vec();
+    let mut keeps = |payoff: f64| {
+        changed[i][j] = StoreType::Float(payoff);
+        initial.is_some() && supports(&changed) == *initial
+    };
+
+    let payoff = matrix[i][j].to_f64();
+    let mut good = payoff;
+    let mut step = FIRST_STEP;
+    let mut bad = loop {
+        let next = if limit > payoff {
+            (payoff + step).min(limit)
+        } else {
+            (payoff - step).max(limit)
+        };
+        if !keeps(next) {
+            break next;
+        }
+        if next == limit {
+            return None;
+        }
+        good = next;
+        step *= 2.0;
+    };
+
+    while (bad - good).abs() > PRECISION {
+        let middle = (good + bad) / 2.0;
+        if keeps(middle) {
+            good = middle;
+        } else {
+            bad = middle;
+        }
+    }
+    Some(good)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::{super::integers, *};
+
+    fn close(bound: Option<f64>, expected: f64) -> bool {
+        bound.is_some_and(|bound| (bound - expected).abs() < 1e-4)
+    }
+
+    #[test]
+    fn bounds_keep_the_supports() {
+        let sensitivity = Sensitivity::analyse(&integers(&[&[3, 1], &[2, 4]]));
+        let (low, high) = sensitivity.bounds[0][0];
+        assert!(close(low, 2.0));
+        assert_eq!(high, None);
+        assert!(close(sensitivity.bounds[0][1].1, 3.0));
+        assert_eq!(sensitivity.cell, (0, 0));
+        assert!(!sensitivity.curve.is_empty());
+    }
+
+    #[test]
+    fn selects_the_curve_of_the_cell() {
+        let matrix = integers(&[&[3, 1], &[2, 4]]);
+        let mut sensitivity = Sensitivity::analyse(&matrix);
+        sensitivity.select(&matrix, (1, 1));
+        assert_eq!(sensitivity.cell, (1, 1));
+        // The value is (a11 * a22 - a12 * a21) / (a11 + a22 - a12 - a21) = 10 / 4 at a22 = 4
+        let (_, value) = sensitivity
+            .curve
+            .iter()
+            .min_by(|x, y| (x.0 - 4.0).abs().total_cmp(&(y.0 - 4.0).abs()))
+            .unwrap();
+        assert!((value - 2.5).abs() < 0.1);
+    }
+}
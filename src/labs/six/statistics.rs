@@ -0,0 +1,153 @@
+use rand_chacha::ChaCha8Rng;
+
+use super::{mixed::MixedSolution, now, seeded_rng, Game, NumberKind, StoreType};
+
+// Milliseconds spent on games per frame, keeps the UI responsive
+const FRAME_TIME: f64 = 20.0;
+
+// Histogram bars over the value range
+pub const BINS: usize = 24;
+
+// Monte Carlo statistics of uniformly random games, accumulated across frames
+#[derive(Clone, Debug)]
+pub struct Statistics {
+    // Parameters
+    pub length: (usize, usize),
+    pub value_range: (i64, i64),
+    pub kind: NumberKind,
+    pub count: usize, // Games to generate
+    pub seed: u64,
+
+    // Accumulated result
+    pub generated: usize,
+    pub saddles: usize,      // Games with a saddle point
+    pub gaps: f64,           // Sum of min(max(Bj)) - max(min(Ai))
+    pub values: f64,         // Sum of game values
+    pub squares: f64,        // Sum of squared game values
+    pub bins: Vec<usize>,    // Histogram of game values
+    rng: Option<ChaCha8Rng>, // Present while generating
+}
+
+impl Statistics {
+    pub const COUNT: usize = 5000;
+
+    // Resets the result and starts generating games
+    pub fn start(&mut self) {
+        self.generated = 0;
+        self.saddles = 0;
+        self.gaps = 0.0;
+        self.values = 0.0;
+        self.squares = 0.0;
+        self.bins = vec![0; BINS];
+        self.rng = Some(seeded_rng(self.seed));
+    }
+
+    pub fn stop(&mut self) {
+        self.rng = None;
+    }
+
+    pub fn running(&self) -> bool {
+        self.rng.is_some()
+    }
+
+    // Generates games until the time of the frame is over
+    pub fn step(&mut self) {
+        let mut rng = match self.rng.take() {
+            Some(rng) => rng,
+            None => return,
+        };
+
+        let deadline = now() + FRAME_TIME;
+        let (lo, hi) = (self.value_range.0 as f64, self.value_range.1 as f64);
+        while self.generated < self.count && now() < deadline {
+            let matrix = (0..self.length.0)
+                .map(|_| {
+                    (0..self.length.1)
+                        .map(|_| StoreType::random(&mut rng, self.kind, self.value_range))
+                        .collect()
+                })
+                .collect::<Vec<Vec<StoreType>>>();
+            let (max_min, min_max) = bounds(&matrix);
+
+            let value = if max_min == min_max {
+                self.saddles += 1;
+                min_max.to_f64()
+            } else {
+                MixedSolution::solve(&matrix).map_or(f64::NAN, |mixed| mixed.value)
+            };
+            if value.is_finite() {
+                let bin = if hi > lo {
+                    ((value - lo) / (hi - lo) * BINS as f64) as usize
+                } else {
+                    0
+                };
+                self.bins[bin.min(BINS - 1)] += 1;
+                self.values += value;
+                self.squares += value * value;
+            }
+            self.gaps += (min_max.to_f64() - max_min.to_f64()).abs();
+            self.generated += 1;
+        }
+
+        if self.generated < self.count {
+            self.rng = Some(rng);
+        }
+    }
+
+    // Empirical probability of the saddle point
+    pub fn saddle_probability(&self) -> f64 {
+        self.saddles as f64 / self.generated as f64
+    }
+
+    pub fn average_gap(&self) -> f64 {
+        self.gaps / self.generated as f64
+    }
+
+    // Mean and standard deviation of game values
+    pub fn value_moments(&self) -> (f64, f64) {
+        let count = self.bins.iter().sum::<usize>() as f64;
+        let mean = self.values / count;
+        let variance = (self.squares / count - mean * mean).max(0.0);
+        (mean, variance.sqrt())
+    }
+
+    // Bounds of the histogram bar
+    pub fn bin_range(&self, bin: usize) -> (f64, f64) {
+        let (lo, hi) = (self.value_range.0 as f64, self.value_range.1 as f64);
+        let width = (hi - lo) / BINS as f64;
+        (lo + width * bin as f64, lo + width * (bin + 1) as f64)
+    }
+}
+
+// Lower and upper values of the game, max(min(Ai)) and min(max(Bj))
+fn bounds(matrix: &[Vec<StoreType>]) -> (StoreType, StoreType) {
+    let max_min = matrix
+        .iter()
+        .map(|row| *row.iter().min().unwrap())
+        .max()
+        .unwrap();
+    let min_max = (0..matrix[0].len())
+        .map(|j| matrix.iter().map(|row| row[j]).max().unwrap())
+        .min()
+        .unwrap();
+    (max_min, min_max)
+}
+
+impl Default for Statistics {
+    fn default() -> Self {
+        Self {
+            length: (3, 3),
+            value_range: Game::VALUE_RANGE,
+            kind: NumberKind::default(),
+            count: Self::COUNT,
+            seed: 0,
+            generated: 0,
+            saddles: 0,
+            gaps: 0.0,
+            values: 0.0,
+            squares: 0.0,
+            bins: vec![0; BINS],
+            rng: None,
+        }
+    }
+}
@@ -0,0 +1,263 @@
+use serde::{Deserialize, Serialize};
+use serde_json::Value;
+
+use super::{Game, MixedSolution, NumberKind, StoreType, LENGTH_RANGE};
+
+#[derive(Clone, Copy, Debug, PartialEq)]
+pub enum Format {
+    Csv,
+    Json,
+}
+
+impl Format {
+    pub const ALL: [Format; 2] = [Format::Csv, Format::Json];
+
+    pub fn as_str(&self) -> &str {
+        match self {
+            Format::Csv => "CSV",
+            Format::Json => "JSON",
+        }
+    }
+
+    pub fn extension(&self) -> &str {
+        match self {
+            Format::Csv => "csv",
+            Format::Json => "json",
+        }
+    }
+}
+
+// JSON representation of the game, results are only written
+#[derive(Serialize, Deserialize)]
+struct Document {
+    kind: String,
+    rows: usize,
+    columns: usize,
+    matrix: Vec<Vec<Value>>,
+    #[serde(default)]
+    matrix_b: Option<Vec<Vec<Value>>>,
+    #[serde(skip_deserializing)]
+    results: Option<Results>,
+}
+
+#[derive(Serialize)]
+struct Results {
+    a_min: Vec<Value>,
+    b_max: Vec<Value>,
+    max_min: Value,
+    min_max: Value,
+    saddle_points: Vec<(usize, usize)>,
+    mixed: Option<MixedSolution>,
+}
+
+// Integers and floats are written as JSON numbers, fractions as "a/b" strings
+fn to_value(val: &StoreType) -> Value {
+    match val {
+        StoreType::Integer(val) => Value::from(*val),
+        StoreType::Float(val) => Value::from(*val),
+        StoreType::Rational(val) => Value::from(val.to_string()),
+    }
+}
+
+// Integers written as floats like 2.0 are accepted as well
+fn from_value(val: &Value, kind: NumberKind) -> Result<StoreType, String> {
+    match val {
+        Value::Number(number) => match number.as_f64() {
+            Some(float)
+                if kind == NumberKind::Integer
+                    && number.is_f64()
+                    && float.fract() == 0.0
+                    && float.abs() < i64::MAX as f64 =>
+            {
+                Ok(StoreType::Integer(float as i64))
+            }
+            _ => StoreType::parse(&number.to_string(), kind),
+        },
+        Value::String(text) => StoreType::parse(text, kind),
+        _ => Err(format!("Invalid value {val}")),
+    }
+}
+
+fn to_values(matrix: &[Vec<StoreType>]) -> Vec<Vec<Value>> {
+    matrix
+        .iter()
+        .map(|row| row.iter().map(to_value).collect())
+        .collect()
+}
+
+fn from_values(matrix: &[Vec<Value>], kind: NumberKind) -> Result<Vec<Vec<StoreType>>, String> {
+    matrix
+        .iter()
+        .map(|row| row.iter().map(|val| from_value(val, kind)).collect())
+        .collect()
+}
+
+impl Game {
+    pub fn save(&mut self, format: Format) -> String {
+        match format {
+            Format::Csv => self
+                .matrix
+                .iter()
+                .map(|row| {
+                    row.iter()
+                        .map(ToString::to_string)
+                        .collect::<Vec<String>>()
+                        .join(",")
+                })
+                .collect::<Vec<String>>()
+                .join("\n"),
+            Format::Json => {
+                let document = Document {
+                    kind: self.kind.as_str().to_string(),
+                    rows: self.rows,
+                    columns: self.columns,
+                    matrix: to_values(&self.matrix),
+                    matrix_b: Some(to_values(&self.matrix_b)),
+                    results: Some(Results {
+                        a_min: self.a_min.iter().map(to_value).collect(),
+                        b_max: self.b_max.iter().map(to_value).collect(),
+                        max_min: to_value(&self.max_min),
+                        min_max: to_value(&self.min_max),
+                        saddle_points: self.saddle_points.clone(),
+                        mixed: self.mixed().cloned(),
+                    }),
+                };
+                serde_json::to_string_pretty(&document).unwrap()
+            }
+        }
+    }
+
+    // Replaces the game with the loaded one, the format is detected from the content
+    pub fn load(&mut self, text: &str) -> Result<(), String> {
+        if text.trim_start().starts_with('{') {
+            let document =
+                serde_json::from_str::<Document>(text).map_err(|err| format!("JSON: {err}"))?;
+
+            let kind = NumberKind::ALL
+                .iter()
+                .find(|kind| kind.as_str() == document.kind)
+                .copied()
+                .ok_or_else(|| format!("Unknown number kind \"{}\"", document.kind))?;
+            let matrix = from_values(&document.matrix, kind)?;
+            let size_matches = |matrix: &[Vec<StoreType>]| {
+                matrix.len() == document.rows
+                    && matrix.iter().all(|row| row.len() == document.columns)
+            };
+            if !size_matches(&matrix) {
+                return Err(format!(
+                    "Matrix doesn't match the {}x{} size",
+                    document.rows, document.columns
+                ));
+            }
+            if !LENGTH_RANGE.contains(&document.rows) || !LENGTH_RANGE.contains(&document.columns) {
+                return Err(format!(
+                    "Matrix size must be in range {}..={}",
+                    LENGTH_RANGE.start(),
+                    LENGTH_RANGE.end()
+                ));
+            }
+            let matrix_b = match &document.matrix_b {
+                Some(matrix_b) => {
+                    let matrix_b = from_values(matrix_b, kind)?;
+                    if !size_matches(&matrix_b) {
+                        return Err("Matrix of B doesn't match the matrix of A".to_string());
+                    }
+                    Some(matrix_b)
+                }
+                None => None,
+            };
+
+            self.kind = kind;
+            self.matrix_b = matrix_b.unwrap_or_else(|| {
+                vec![vec![StoreType::zero(kind); document.columns]; document.rows]
+            });
+            self.set_matrix(matrix);
+        } else {
+            // Current kind is preferred, others are tried from the most specific one
+            let (kind, matrix) = match [self.kind]
+                .iter()
+                .chain(NumberKind::ALL.iter())
+                .find_map(|&kind| Game::parse(text, kind).ok().map(|matrix| (kind, matrix)))
+            {
+                Some(loaded) => loaded,
+                None => return Game::parse(text, self.kind).map(|_| ()),
+            };
+
+            self.kind = kind;
+            self.set_matrix(matrix);
+        }
+
+        Ok(())
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn game(kind: NumberKind) -> Game {
+        let mut game = Game {
+            kind,
+            value_range: (-20, 20),
+            seed: 7,
+            ..Default::default()
+        };
+        game.resize((3, 4));
+        game
+    }
+
+    #[test]
+    fn round_trips_every_kind() {
+        NumberKind::ALL.into_iter().for_each(|kind| {
+            let mut saved = game(kind);
+            let mut loaded = game(kind);
+            loaded.resize((2, 2));
+            loaded.load(&saved.save(Format::Csv)).unwrap();
+            assert_eq!(loaded.matrix, saved.matrix);
+
+            let mut loaded = Game::default();
+            loaded.load(&saved.save(Format::Json)).unwrap();
+            assert_eq!(loaded.kind, kind);
+            assert_eq!(loaded.matrix, saved.matrix);
+            assert_eq!(loaded.matrix_b, saved.matrix_b);
+        });
+    }
+
+    #[test]
+    fn round_trips_without_matrix_of_b() {
+        NumberKind::ALL.into_iter().for_each(|kind| {
+            let mut saved = game(kind);
+            let mut document = serde_json::from_str::<Value>(&saved.save(Format::Json)).unwrap();
+            document.as_object_mut().unwrap().remove("matrix_b");
+
+            let mut loaded = game(kind);
+            loaded.seed += 1;
+            loaded.randomize();
+            loaded.load(&document.to_string()).unwrap();
+            assert_eq!(loaded.matrix, saved.matrix);
+            assert!(loaded
+                .matrix_b
+                .iter()
+                .flatten()
+                .all(|val| val.to_f64() == 0.0));
+        });
+    }
+
+    #[test]
+    fn loads_integral_floats_as_integers() {
+        let mut game = Game::default();
+        let text =
+            r#"{"kind": "Integer", "rows": 2, "columns": 2, "matrix": [[2.0, -1], [0, 3e0]]}"#;
+        game.load(text).unwrap();
+        assert_eq!(
+            game.matrix,
+            [
+                vec![StoreType::Integer(2), StoreType::Integer(-1)],
+                vec![StoreType::Integer(0), StoreType::Integer(3)]
+            ]
+        );
+        assert!(game
+            .load(r#"{"kind": "Integer", "rows": 2, "columns": 2, "matrix": [[2.5, 1], [0, 3]]}"#)
+            .is_err());
+    }
+}
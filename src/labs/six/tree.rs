@@ -0,0 +1,429 @@
+use super::{dominance::Player, Game, Rational, LENGTH_RANGE};
+
+#[derive(Clone, Copy, Debug, PartialEq)]
+pub enum NodeKind {
+    Decision(Player),
+    Chance,
+    Terminal,
+}
+
+impl NodeKind {
+    pub const ALL: [NodeKind; 4] = [
+        NodeKind::Decision(Player::A),
+        NodeKind::Decision(Player::B),
+        NodeKind::Chance,
+        NodeKind::Terminal,
+    ];
+
+    pub fn as_str(&self) -> &str {
+        match self {
+            NodeKind::Decision(Player::A) => "Player A",
+            NodeKind::Decision(Player::B) => "Player B",
+            NodeKind::Chance => "Chance",
+            NodeKind::Terminal => "Terminal",
+        }
+    }
+}
+
+#[derive(Clone, Debug)]
+pub struct Node {
+    pub kind: NodeKind,
+    pub info_set: usize, // Decision nodes of a player in one set can't be told apart
+    pub payoff: [Rational; 2], // Payoffs of A and B at the terminal node
+    pub action: String,  // Label of the edge from the parent
+    pub probability: Rational, // Probability of the edge from the chance node
+    pub parent: Option<usize>,
+    pub children: Vec<usize>,
+}
+
+impl Node {
+    fn new(kind: NodeKind, parent: Option<usize>, action: &str) -> Self {
+        Self {
+            kind,
+            info_set: 0,
+            payoff: [Rational::integer(0); 2],
+            action: action.to_string(),
+            probability: Rational::integer(1),
+            parent,
+            children: Vec::new(),
+        }
+    }
+}
+
+// Information set of the player and labels of its actions, taken from its first node
+#[derive(Clone, Debug)]
+pub struct InfoSet {
+    pub id: usize,
+    pub actions: Vec<String>,
+}
+
+// Normal form of the tree, a pure strategy chooses an action in every information set
+#[derive(Clone, Debug)]
+pub struct NormalForm {
+    pub matrix_a: Vec<Vec<Rational>>,
+    pub matrix_b: Vec<Vec<Rational>>,
+    pub strategies_a: Vec<String>, // Chosen actions joined by "/"
+    pub strategies_b: Vec<String>,
+}
+
+// Finite game in the extensive form, the root is the first node
+#[derive(Clone, Debug)]
+pub struct Tree {
+    pub nodes: Vec<Node>,
+}
+
+impl Tree {
+    pub fn add_child(&mut self, parent: usize) -> usize {
+        let index = self.nodes.len();
+        let action = format!("a{}", self.nodes[parent].children.len() + 1);
+        self.nodes
+            .push(Node::new(NodeKind::Terminal, Some(parent), &action));
+        self.nodes[parent].children.push(index);
+
+        // New chance outcomes split the probability evenly
+        if self.nodes[parent].kind == NodeKind::Chance {
+            let children = self.nodes[parent].children.clone();
+            let probability = Rational::new(1, children.len() as i64);
+            children
+                .iter()
+                .for_each(|&child| self.nodes[child].probability = probability);
+        }
+        index
+    }
+
+    // Removes the node with its subtree, the root stays
+    pub fn remove(&mut self, node: usize) {
+        if node == 0 {
+            return;
+        }
+        let mut removed = vec![false; self.nodes.len()];
+        let mut stack = vec![node];
+        while let Some(current) = stack.pop() {
+            removed[current] = true;
+            stack.extend(self.nodes[current].children.iter().copied());
+        }
+
+        let mut index = vec![0; self.nodes.len()];
+        let mut next = 0;
+        removed.iter().enumerate().for_each(|(i, &gone)| {
+            if !gone {
+                index[i] = next;
+                next += 1;
+            }
+        });
+
+        let nodes = std::mem::take(&mut self.nodes);
+        self.nodes = nodes
+            .into_iter()
+            .enumerate()
+            .filter(|(i, _)| !removed[*i])
+            .map(|(_, mut current)| {
+                current.parent = current.parent.map(|parent| index[parent]);
+                current.children = current
+                    .children
+                    .iter()
+                    .filter(|&&child| !removed[child])
+                    .map(|&child| index[child])
+                    .collect();
+                current
+            })
+            .collect();
+    }
+
+    // Changes the kind of the node, terminal nodes lose their subtrees
+    pub fn set_kind(&mut self, node: usize, kind: NodeKind) {
+        if kind == NodeKind::Terminal {
+            self.nodes[node]
+                .children
+                .clone()
+                .into_iter()
+                .rev()
+                .for_each(|child| self.remove(child));
+        }
+        self.nodes[node].kind = kind;
+    }
+
+    // Positions of the nodes: leaves are one unit apart, parents are centered above
+    // their children and every level is one unit lower
+    pub fn layout(&self) -> Vec<(f64, f64)> {
+        let mut positions = vec![(0.0, 0.0); self.nodes.len()];
+        let mut leaf = 0.0;
+        self.place(0, 0, &mut leaf, &mut positions);
+        positions
+    }
+
+    fn place(&self, node: usize, depth: usize, leaf: &mut f64, positions: &mut [(f64, f64)]) {
+        let children = &self.nodes[node].children;
+        let x = if children.is_empty() {
+            *leaf += 1.0;
+            *leaf - 1.0
+        } else {
+            children
+                .iter()
+                .for_each(|&child| self.place(child, depth + 1, leaf, positions));
+            (positions[children[0]].0 + positions[children[children.len() - 1]].0) / 2.0
+        };
+        positions[node] = (x, -(depth as f64));
+    }
+
+    // Information sets of the player in the order of their first nodes
+    pub fn info_sets(&self, player: Player) -> Vec<InfoSet> {
+        let mut sets = Vec::<InfoSet>::new();
+        self.nodes
+            .iter()
+            .filter(|node| node.kind == NodeKind::Decision(player))
+            .for_each(|node| {
+                if sets.iter().all(|set| set.id != node.info_set) {
+                    sets.push(InfoSet {
+                        id: node.info_set,
+                        actions: node
+                            .children
+                            .iter()
+                            .map(|&child| self.nodes[child].action.clone())
+                            .collect(),
+                    });
+                }
+            });
+        sets
+    }
+
+    // Whether the payoff of B is the loss of A everywhere
+    pub fn is_zero_sum(&self) -> bool {
+        self.nodes
+            .iter()
+            .filter(|node| node.kind == NodeKind::Terminal)
+            .all(|node| node.payoff[0].checked_add(node.payoff[1]) == Some(Rational::integer(0)))
+    }
+
+    pub fn validate(&self) -> Result<(), String> {
+        for (i, node) in self.nodes.iter().enumerate() {
+            match node.kind {
+                NodeKind::Terminal => {
+                    if !node.children.is_empty() {
+                        return Err(format!("Terminal node {i} has actions"));
+                    }
+                }
+                _ if node.children.is_empty() => {
+                    return Err(format!("Node {i} has no actions"));
+                }
+                NodeKind::Chance => {
+                    let mut total = Rational::integer(0);
+                    for &child in &node.children {
+                        let probability = self.nodes[child].probability;
+                        if probability < Rational::integer(0) {
+                            return Err(format!(
+                                "Negative probability {probability} of node {child}"
+                            ));
+                        }
+                        total = total
+                            .checked_add(probability)
+                            .ok_or_else(|| format!("Probabilities of node {i} overflow"))?;
+                    }
+                    if total != Rational::integer(1) {
+                        return Err(format!("Probabilities of node {i} sum to {total}, not 1"));
+                    }
+                }
+                NodeKind::Decision(player) => {
+                    let first = self
+                        .nodes
+                        .iter()
+                        .position(|other| {
+                            other.kind == node.kind && other.info_set == node.info_set
+                        })
+                        .unwrap();
+                    if self.nodes[first].children.len() != node.children.len() {
+                        return Err(format!(
+                            "Nodes {first} and {i} of the set {}:{} have different actions count",
+                            player.as_str(),
+                            node.info_set
+                        ));
+                    }
+                }
+            }
+        }
+        Ok(())
+    }
+
+    // Pure strategies of the player as the action chosen in each information set
+    fn strategies(&self, player: Player) -> Result<Vec<Vec<usize>>, String> {
+        let sets = self.info_sets(player);
+        let count = sets
+            .iter()
+            .try_fold(1usize, |count, set| count.checked_mul(set.actions.len()))
+            .filter(|count| count <= LENGTH_RANGE.end());
+        match count {
+            Some(count) if LENGTH_RANGE.contains(&count) => {}
+            Some(count) => {
+                return Err(format!(
+                    "Player {} has {count} pure strategies, at least {} are needed",
+                    player.as_str(),
+                    LENGTH_RANGE.start()
+                ))
+            }
+            None => {
+                return Err(format!(
+                    "Player {} has more than {} pure strategies",
+                    player.as_str(),
+                    LENGTH_RANGE.end()
+                ))
+            }
+        }
+
+        // Counting in the mixed radix, the first information set changes the slowest
+        let mut strategies = vec![Vec::new()];
+        for set in &sets {
+            strategies = strategies
+                .into_iter()
+                .flat_map(|strategy| {
+                    (0..set.actions.len()).map(move |action| {
+                        let mut strategy = strategy.clone();
+                        strategy.push(action);
+                        strategy
+                    })
+                })
+                .collect();
+        }
+        Ok(strategies)
+    }
+
+    fn strategy_label(sets: &[InfoSet], strategy: &[usize]) -> String {
+        sets.iter()
+            .zip(strategy.iter())
+            .map(|(set, &action)| set.actions[action].as_str())
+            .collect::<Vec<&str>>()
+            .join("/")
+    }
+
+    // Expected payoffs of the node when players follow the given choices
+    fn expected(
+        &self,
+        node: usize,
+        choice: &dyn Fn(Player, usize) -> usize,
+    ) -> Option<[Rational; 2]> {
+        let node = &self.nodes[node];
+        match node.kind {
+            NodeKind::Terminal => Some(node.payoff),
+            NodeKind::Decision(player) => {
+                self.expected(node.children[choice(player, node.info_set)], choice)
+            }
+            NodeKind::Chance => {
+                node.children
+                    .iter()
+                    .try_fold([Rational::integer(0); 2], |total, &child| {
+                        let probability = self.nodes[child].probability;
+                        let payoff = self.expected(child, choice)?;
+                        Some([
+                            total[0].checked_add(probability.checked_mul(payoff[0])?)?,
+                            total[1].checked_add(probability.checked_mul(payoff[1])?)?,
+                        ])
+                    })
+            }
+        }
+    }
+
+    pub fn normal_form(&self) -> Result<NormalForm, String> {
+        self.validate()?;
+        let sets = [self.info_sets(Player::A), self.info_sets(Player::B)];
+        let strategies = [self.strategies(Player::A)?, self.strategies(Player::B)?];
+
+        let mut matrix_a = Vec::with_capacity(strategies[0].len());
+        let mut matrix_b = Vec::with_capacity(strategies[0].len());
+        for row in &strategies[0] {
+            let (mut payoffs_a, mut payoffs_b) = (Vec::new(), Vec::new());
+            for column in &strategies[1] {
+                let choice = |player: Player, id: usize| {
+                    let (sets, strategy) = match player {
+                        Player::A => (&sets[0], row),
+                        Player::B => (&sets[1], column),
+                    };
+                    strategy[sets.iter().position(|set| set.id == id).unwrap()]
+                };
+                let payoff = self
+                    .expected(0, &choice)
+                    .ok_or("Expected payoffs overflow")?;
+                payoffs_a.push(payoff[0]);
+                payoffs_b.push(payoff[1]);
+            }
+            matrix_a.push(payoffs_a);
+            matrix_b.push(payoffs_b);
+        }
+
+        Ok(NormalForm {
+            matrix_a,
+            matrix_b,
+            strategies_a: strategies[0]
+                .iter()
+                .map(|strategy| Self::strategy_label(&sets[0], strategy))
+                .collect(),
+            strategies_b: strategies[1]
+                .iter()
+                .map(|strategy| Self::strategy_label(&sets[1], strategy))
+                .collect(),
+        })
+    }
+}
+
+// Simplified poker: A gets a high or a low card and raises or folds, B doesn't see
+// the card and calls or folds after the raise
+impl Default for Tree {
+    fn default() -> Self {
+        let mut tree = Tree {
+            nodes: vec![Node::new(NodeKind::Chance, None, "")],
+        };
+        for (card, win) in [("High", 2), ("Low", -2)] {
+            let deal = tree.add_child(0);
+            tree.nodes[deal].action = card.to_string();
+            tree.set_kind(deal, NodeKind::Decision(Player::A));
+            tree.nodes[deal].info_set = usize::from(win < 0);
+
+            let raise = tree.add_child(deal);
+            tree.nodes[raise].action = "Raise".to_string();
+            tree.set_kind(raise, NodeKind::Decision(Player::B));
+            for (action, payoff) in [("Call", win), ("Fold", 1)] {
+                let end = tree.add_child(raise);
+                tree.nodes[end].action = action.to_string();
+                tree.nodes[end].payoff = [Rational::integer(payoff), Rational::integer(-payoff)];
+            }
+
+            let fold = tree.add_child(deal);
+            tree.nodes[fold].action = "Fold".to_string();
+            tree.nodes[fold].payoff = [Rational::integer(-1), Rational::integer(1)];
+        }
+        tree
+    }
+}
+
+impl Game {
+    // Replaces the matrices by the normal form of the tree, the kind becomes
+    // rational if chance makes some expected payoffs fractional
+    pub fn load_tree(&mut self, tree: &Tree) -> Result<NormalForm, String> {
+        let normal_form = tree.normal_form()?;
+        self.set_rationals(&normal_form.matrix_a, &normal_form.matrix_b);
+        Ok(normal_form)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::{
+        super::{mixed::MixedSolution, StoreType},
+        *,
+    };
+
+    #[test]
+    fn converts_poker_to_normal_form() {
+        let tree = Tree::default();
+        assert!(tree.is_zero_sum());
+        let normal_form = tree.normal_form().unwrap();
+        assert_eq!(normal_form.strategies_a.len(), 4);
+        assert_eq!(normal_form.strategies_b.len(), 2);
+
+        let matrix = normal_form
+            .matrix_a
+            .iter()
+            .map(|row| row.iter().map(|&val| StoreType::Rational(val)).collect())
+            .collect::<Vec<Vec<StoreType>>>();
+        let exact = MixedSolution::solve(&matrix).unwrap().exact.unwrap();
+        assert_eq!(exact.value, Rational::new(1, 3));
+    }
+}
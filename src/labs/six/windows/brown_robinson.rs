@@ -0,0 +1,158 @@
+use std::ops::RangeInclusive;
+
+use eframe::egui::{
+    plot::{HLine, Legend, Line, Plot, Value, Values},
+    Color32, CtxRef, DragValue, Grid, Window,
+};
+
+use super::super::{index_text, Column, Row, SixthLab, Table};
+
+const ITERATIONS_RANGE: RangeInclusive<usize> = 1..=500;
+
+impl SixthLab {
+    pub(super) fn brown_robinson_window(&mut self, ctx: &CtxRef) {
+        Window::new("Brown-Robinson Method")
+            .open(&mut self.brown_robinson_viewer)
+            .show(ctx, |ui| {
+                if ui
+                    .add(
+                        DragValue::new(&mut self.game.iterations)
+                            .speed(1.0)
+                            .prefix("Iterations: ")
+                            .clamp_range(ITERATIONS_RANGE),
+                    )
+                    .changed()
+                {
+                    self.game.brown_robinson = None;
+                }
+
+                let (rows, columns) = (self.game.rows, self.game.columns);
+                let exact = self.game.exact();
+                let brown_robinson = self.game.brown_robinson();
+
+                ui.label(format!(
+                    "{:.4} <= V <= {:.4}, V ≈ {:.4}",
+                    brown_robinson.lower,
+                    brown_robinson.upper,
+                    brown_robinson.value()
+                ));
+                if let Some(exact) = &exact {
+                    ui.label(format!(
+                        "Exact V = {:.4}, error = {:.4}",
+                        exact.value,
+                        (brown_robinson.value() - exact.value).abs()
+                    ));
+                }
+
+                Plot::new("brown_robinson")
+                    .view_aspect(2.0)
+                    .legend(Legend::default())
+                    .show(ui, |plot| {
+                        plot.line(
+                            Line::new(Values::from_values_iter(
+                                brown_robinson.iterations.iter().enumerate().map(
+                                    |(k, iteration)| Value::new((k + 1) as f64, iteration.upper),
+                                ),
+                            ))
+                            .color(Color32::RED)
+                            .name("Upper"),
+                        );
+                        plot.line(
+                            Line::new(Values::from_values_iter(
+                                brown_robinson.iterations.iter().enumerate().map(
+                                    |(k, iteration)| Value::new((k + 1) as f64, iteration.lower),
+                                ),
+                            ))
+                            .color(Color32::BLUE)
+                            .name("Lower"),
+                        );
+                        if let Some(exact) = &exact {
+                            plot.hline(HLine::new(exact.value).color(Color32::GREEN).name("V"));
+                        }
+                    });
+
+                // Frequencies comparison
+                Grid::new("brown_robinson_frequencies")
+                    .striped(true)
+                    .show(ui, |grid| {
+                        grid.label("Strategy");
+                        grid.label("Frequency");
+                        if exact.is_some() {
+                            grid.label("Exact");
+                        }
+                        grid.end_row();
+
+                        let strategies =
+                            brown_robinson
+                                .a_frequencies
+                                .iter()
+                                .enumerate()
+                                .map(|(i, freq)| ("A", i, freq, exact.as_ref().map(|e| e.a[i])))
+                                .chain(brown_robinson.b_frequencies.iter().enumerate().map(
+                                    |(j, freq)| ("B", j, freq, exact.as_ref().map(|e| e.b[j])),
+                                ));
+                        strategies.for_each(|(prefix, i, freq, exact)| {
+                            grid.label(index_text(prefix, i));
+                            grid.label(format!("{freq:.4}"));
+                            if let Some(exact) = exact {
+                                grid.label(format!("{exact:.4}"));
+                            }
+                            grid.end_row();
+                        });
+                    });
+
+                // Iterations table, the label column is k
+                ui.collapsing("Iterations Table", |ui| {
+                    let iterations = &brown_robinson.iterations;
+                    Table::new("brown_robinson_table", iterations.len(), rows + columns + 4)
+                        .cell_width(64.0)
+                        .max_height(256.0)
+                        .show(ui, |cell, row, column| match (row, column) {
+                            (Row::Header, Column::Label) => {
+                                cell.label("k");
+                            }
+                            (Row::Header, Column::Body(0)) => {
+                                cell.label("A");
+                            }
+                            (Row::Header, Column::Body(1)) => {
+                                cell.label("B");
+                            }
+                            (Row::Header, Column::Body(j)) if j < rows + 2 => {
+                                cell.label(index_text("A", j - 2));
+                            }
+                            (Row::Header, Column::Body(j)) if j < rows + columns + 2 => {
+                                cell.label(index_text("B", j - rows - 2));
+                            }
+                            (Row::Header, Column::Body(j)) if j == rows + columns + 2 => {
+                                cell.label("V̄");
+                            }
+                            (Row::Header, Column::Body(_)) => {
+                                cell.label("V̲");
+                            }
+                            (Row::Body(k), Column::Label) => {
+                                cell.label((k + 1).to_string());
+                            }
+                            (Row::Body(k), Column::Body(0)) => {
+                                cell.label(index_text("A", iterations[k].a));
+                            }
+                            (Row::Body(k), Column::Body(1)) => {
+                                cell.label(index_text("B", iterations[k].b));
+                            }
+                            (Row::Body(k), Column::Body(j)) if j < rows + 2 => {
+                                cell.label(iterations[k].a_gains[j - 2].to_string());
+                            }
+                            (Row::Body(k), Column::Body(j)) if j < rows + columns + 2 => {
+                                cell.label(iterations[k].b_losses[j - rows - 2].to_string());
+                            }
+                            (Row::Body(k), Column::Body(j)) if j == rows + columns + 2 => {
+                                cell.label(format!("{:.4}", iterations[k].upper));
+                            }
+                            (Row::Body(k), Column::Body(_)) => {
+                                cell.label(format!("{:.4}", iterations[k].lower));
+                            }
+                            (Row::Footer, _) => {}
+                        });
+                });
+            });
+    }
+}
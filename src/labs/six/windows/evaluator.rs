@@ -0,0 +1,117 @@
+use eframe::egui::{Color32, CtxRef, RichText, TextEdit, Window};
+
+use super::super::{
+    cell_width, format_value, index_text, Column, Evaluation, NumberKind, Player, Rational, Row,
+    SixthLab, Table, OPTIMAL_COLOR,
+};
+
+impl SixthLab {
+    pub(super) fn evaluator_window(&mut self, ctx: &CtxRef) {
+        Window::new("Strategy Evaluator")
+            .open(&mut self.evaluator_viewer)
+            .show(ctx, |ui| {
+                let player = &mut self.evaluator_player;
+                let exact = self.game.exact();
+                ui.horizontal(|hor| {
+                    hor.label("Strategy of");
+                    [Player::A, Player::B].iter().for_each(|option| {
+                        hor.selectable_value(player, *option, option.as_str());
+                    });
+                    if let Some(exact) = &exact {
+                        if hor
+                            .button("Optimal")
+                            .on_hover_text("Fill in the optimal strategy")
+                            .clicked()
+                        {
+                            let strategy = match (&exact.exact, *player) {
+                                (Some(fractions), Player::A) => fractions.a.clone(),
+                                (Some(fractions), Player::B) => fractions.b.clone(),
+                                (None, Player::A) => {
+                                    exact.a.iter().map(|p| Rational::from_f64(*p)).collect()
+                                }
+                                (None, Player::B) => {
+                                    exact.b.iter().map(|q| Rational::from_f64(*q)).collect()
+                                }
+                            };
+                            self.evaluator_buffer = strategy
+                                .iter()
+                                .map(Rational::to_string)
+                                .collect::<Vec<String>>()
+                                .join(", ");
+                        }
+                    }
+                });
+                ui.add(
+                    TextEdit::singleline(&mut self.evaluator_buffer)
+                        .hint_text("Probabilities, e.g. 1/4, 3/4")
+                        .desired_width(f32::INFINITY),
+                );
+                if self.evaluator_buffer.trim().is_empty() {
+                    return;
+                }
+
+                let player = *player;
+                let evaluation = Evaluation::parse(&self.evaluator_buffer).and_then(|strategy| {
+                    Evaluation::evaluate(&self.game.matrix, player, &strategy)
+                });
+                let evaluation = match evaluation {
+                    Ok(evaluation) => evaluation,
+                    Err(error) => {
+                        ui.colored_label(Color32::RED, error);
+                        return;
+                    }
+                };
+
+                let opponent = match player {
+                    Player::A => Player::B,
+                    Player::B => Player::A,
+                };
+                ui.label(format!(
+                    "Guaranteed {}: {}",
+                    match player {
+                        Player::A => "payoff",
+                        Player::B => "loss",
+                    },
+                    format_value(evaluation.guaranteed)
+                ));
+                ui.label(format!(
+                    "Best response: {}",
+                    evaluation
+                        .best_responses
+                        .iter()
+                        .map(|k| format!("{}{k}", opponent.as_str()))
+                        .collect::<Vec<String>>()
+                        .join(", ")
+                ));
+                if let Some(exact) = &exact {
+                    ui.label(format!(
+                        "Exploitability: {} (V = {})",
+                        format_value(evaluation.exploitability(player, exact.value)),
+                        format_value(exact.value)
+                    ));
+                }
+
+                ui.add_space(8.0);
+                Table::new("evaluator", 1, evaluation.payoffs.len())
+                    .cell_width(cell_width(NumberKind::Float))
+                    .show(ui, |cell, row, column| match (row, column) {
+                        (Row::Header, Column::Label) => {}
+                        (Row::Header, Column::Body(k)) => {
+                            cell.label(index_text(opponent.as_str(), k));
+                        }
+                        (Row::Body(_), Column::Label) => {
+                            cell.label("Payoff");
+                        }
+                        (Row::Body(_), Column::Body(k)) => {
+                            let text = RichText::new(format_value(evaluation.payoffs[k]));
+                            cell.label(if evaluation.best_responses.contains(&k) {
+                                text.strong().color(OPTIMAL_COLOR)
+                            } else {
+                                text
+                            });
+                        }
+                        (Row::Footer, _) => {}
+                    });
+            });
+    }
+}
@@ -0,0 +1,79 @@
+use eframe::egui::{
+    plot::{Legend, Line, Plot, Points, Value, Values},
+    Color32, CtxRef, Window,
+};
+
+use super::super::SixthLab;
+
+impl SixthLab {
+    pub(super) fn graphical_window(&mut self, ctx: &CtxRef) {
+        Window::new("Graphical Method")
+            .open(&mut self.graphical_viewer)
+            .show(ctx, |ui| {
+                let graphical = match &self.game.graphical {
+                    Some(graphical) => graphical,
+                    None => {
+                        ui.label("The graphical method solves 2xN and Mx2 games");
+                        return;
+                    }
+                };
+
+                let (player, opponent) = if graphical.upper {
+                    ("B", "A")
+                } else {
+                    ("A", "B")
+                };
+
+                Plot::new("graphical_method")
+                    .view_aspect(1.5)
+                    .include_x(0.0)
+                    .include_x(1.0)
+                    .legend(Legend::default())
+                    .show(ui, |plot| {
+                        graphical.lines.iter().enumerate().for_each(|(i, line)| {
+                            plot.line(
+                                Line::new(Values::from_values(vec![
+                                    Value::new(0.0, line.0),
+                                    Value::new(1.0, line.1),
+                                ]))
+                                .name(format!("{opponent}{i}")),
+                            );
+                        });
+                        plot.line(
+                            Line::new(Values::from_values_iter(
+                                graphical.envelope.iter().map(|(x, y)| Value::new(*x, *y)),
+                            ))
+                            .width(3.0)
+                            .color(Color32::YELLOW)
+                            .name("Envelope"),
+                        );
+                        plot.points(
+                            Points::new(Values::from_values(vec![Value::new(
+                                graphical.optimum.0,
+                                graphical.optimum.1,
+                            )]))
+                            .radius(5.0)
+                            .color(Color32::RED)
+                            .highlight()
+                            .name("Optimum"),
+                        );
+                    });
+
+                ui.label(format!(
+                    "p({player}0) = {:.4}, p({player}1) = {:.4}",
+                    graphical.optimum.0,
+                    1.0 - graphical.optimum.0
+                ));
+                ui.label(format!("V = {:.4}", graphical.optimum.1));
+                ui.label(format!(
+                    "Active strategies: {}",
+                    graphical
+                        .active
+                        .iter()
+                        .map(|i| format!("{opponent}{i}"))
+                        .collect::<Vec<String>>()
+                        .join(", ")
+                ));
+            });
+    }
+}
@@ -0,0 +1,51 @@
+use eframe::egui::{ComboBox, CtxRef, DragValue, Vec2, Window};
+
+use super::super::{Mode, Preset, SixthLab};
+
+impl SixthLab {
+    pub(super) fn library_window(&mut self, ctx: &CtxRef) {
+        Window::new("Game Library")
+            .open(&mut self.library_viewer)
+            .show(ctx, |ui| {
+                let preset = self.preset;
+                ComboBox::from_id_source("preset")
+                    .width(ui.available_size_before_wrap().x)
+                    .selected_text(preset.as_str())
+                    .show_ui(ui, |combo| {
+                        Preset::ALL.iter().for_each(|option| {
+                            combo.selectable_value(&mut self.preset, *option, option.as_str());
+                        });
+                    });
+
+                // Parametrized families are loaded again at every change of the size
+                let parameters = self.preset.parameters();
+                let mut changed = self.preset != preset;
+                if changed || self.preset_parameters.len() != parameters.len() {
+                    self.preset_parameters = parameters.iter().map(|param| param.default).collect();
+                }
+                parameters
+                    .into_iter()
+                    .zip(self.preset_parameters.iter_mut())
+                    .for_each(|(param, value)| {
+                        changed |= ui
+                            .add_sized(
+                                Vec2::new(ui.available_size_before_wrap().x, 1.0),
+                                DragValue::new(value)
+                                    .speed(0.05)
+                                    .prefix(param.name)
+                                    .clamp_range(param.range),
+                            )
+                            .changed();
+                    });
+                ui.separator();
+                ui.label(self.preset.description());
+                ui.separator();
+                if ui.button("Load").clicked() || changed {
+                    self.game.load_preset(self.preset, &self.preset_parameters);
+                    self.length = (self.game.rows, self.game.columns);
+                    self.sensitivity_cell = (0, 0);
+                    self.mode = Mode::Game;
+                }
+            });
+    }
+}
@@ -0,0 +1,30 @@
+use eframe::egui::CtxRef;
+
+use super::SixthLab;
+
+mod brown_robinson;
+mod evaluator;
+mod graphical;
+mod library;
+mod paste;
+mod replicator;
+mod report;
+mod sensitivity;
+mod statistics;
+mod tree;
+
+impl SixthLab {
+    // Viewer windows are shown in this order, each one only while it is open
+    pub(super) fn windows(&mut self, ctx: &CtxRef) {
+        self.paste_window(ctx);
+        self.library_window(ctx);
+        self.report_window(ctx);
+        self.statistics_window(ctx);
+        self.evaluator_window(ctx);
+        self.sensitivity_window(ctx);
+        self.replicator_window(ctx);
+        self.tree_window(ctx);
+        self.brown_robinson_window(ctx);
+        self.graphical_window(ctx);
+    }
+}
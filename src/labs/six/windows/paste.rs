@@ -0,0 +1,34 @@
+use eframe::egui::{Color32, CtxRef, TextEdit, Window};
+
+use super::super::{Game, SixthLab};
+
+impl SixthLab {
+    pub(super) fn paste_window(&mut self, ctx: &CtxRef) {
+        Window::new("Paste Matrix")
+            .open(&mut self.paste_viewer)
+            .show(ctx, |ui| {
+                ui.label(
+                    "Rows separated by new lines, values by tabs, spaces, commas or semicolons",
+                );
+                ui.add(
+                    TextEdit::multiline(&mut self.paste_buffer)
+                        .code_editor()
+                        .desired_width(f32::INFINITY),
+                );
+                if let Some(error) = &self.paste_error {
+                    ui.colored_label(Color32::RED, error);
+                }
+                if ui.button("Load").clicked() {
+                    match Game::parse(&self.paste_buffer, self.game.kind) {
+                        Ok(matrix) => {
+                            self.game.set_matrix(matrix);
+                            self.length = (self.game.rows, self.game.columns);
+                            self.sensitivity_cell = (0, 0);
+                            self.paste_error = None;
+                        }
+                        Err(error) => self.paste_error = Some(error),
+                    }
+                }
+            });
+    }
+}
@@ -0,0 +1,151 @@
+use std::ops::RangeInclusive;
+
+use eframe::egui::{
+    plot::{Legend, Line, Plot, Points, Text, Value, Values},
+    Align2, Color32, CtxRef, DragValue, TextEdit, Window,
+};
+
+use super::super::{format_probabilities, Replicator, SixthLab};
+
+// Largest population game, stable strategies are searched over all supports as well
+const REPLICATOR_LIMIT: usize = 10;
+const REPLICATOR_STEPS: RangeInclusive<usize> = 10..=10_000;
+const REPLICATOR_STEP: RangeInclusive<f64> = 0.001..=0.1;
+
+impl SixthLab {
+    pub(super) fn replicator_window(&mut self, ctx: &CtxRef) {
+        Window::new("Replicator Dynamics")
+            .open(&mut self.replicator_viewer)
+            .show(ctx, |ui| {
+                let length = self.game.rows;
+                if length != self.game.columns || length > REPLICATOR_LIMIT {
+                    ui.label(format!(
+                        "Population games need a square matrix up to {REPLICATOR_LIMIT}x{REPLICATOR_LIMIT}"
+                    ));
+                    return;
+                }
+
+                ui.add(
+                    TextEdit::singleline(&mut self.replicator_buffer)
+                        .hint_text("Initial shares, e.g. 1/2, 1/4, 1/4")
+                        .desired_width(f32::INFINITY),
+                );
+                ui.horizontal(|hor| {
+                    hor.add(
+                        DragValue::new(&mut self.replicator_steps)
+                            .speed(10.0)
+                            .prefix("Steps: ")
+                            .clamp_range(REPLICATOR_STEPS),
+                    );
+                    hor.add(
+                        DragValue::new(&mut self.replicator_step)
+                            .speed(0.001)
+                            .max_decimals(3)
+                            .prefix("dt: ")
+                            .clamp_range(REPLICATOR_STEP),
+                    );
+                });
+                let initial = match Replicator::parse_initial(&self.replicator_buffer, length) {
+                    Ok(initial) => initial,
+                    Err(error) => {
+                        ui.colored_label(Color32::RED, error);
+                        return;
+                    }
+                };
+                let replicator =
+                    self.game
+                        .replicator(&initial, self.replicator_steps, self.replicator_step);
+
+                if replicator.stable.is_empty() {
+                    ui.label("No evolutionarily stable strategies");
+                }
+                replicator.stable.iter().for_each(|stable| {
+                    ui.label(format!("ESS: {}", format_probabilities(stable)));
+                });
+                if let Some(last) = replicator.trajectory.last() {
+                    ui.label(format!("Final shares: {}", format_probabilities(last)));
+                }
+
+                Plot::new("replicator_shares")
+                    .view_aspect(2.0)
+                    .include_y(0.0)
+                    .include_y(1.0)
+                    .legend(Legend::default())
+                    .show(ui, |plot| {
+                        (0..length).for_each(|i| {
+                            plot.line(
+                                Line::new(Values::from_values_iter(
+                                    replicator.trajectory.iter().enumerate().map(|(k, shares)| {
+                                        Value::new(k as f64 * self.replicator_step, shares[i])
+                                    }),
+                                ))
+                                .name(format!("A{i}")),
+                            );
+                        });
+                    });
+
+                if length == 3 {
+                    // Barycentric coordinates, A0, A1 and A2 are the corners of the triangle
+                    let point = |shares: &[f64]| {
+                        Value::new(shares[1] + shares[2] / 2.0, shares[2] * 3f64.sqrt() / 2.0)
+                    };
+                    let corners = [[1.0, 0.0, 0.0], [0.0, 1.0, 0.0], [0.0, 0.0, 1.0]];
+                    Plot::new("replicator_simplex")
+                        .data_aspect(1.0)
+                        .view_aspect(1.2)
+                        .show_axes([false, false])
+                        .show_x(false)
+                        .show_y(false)
+                        .legend(Legend::default())
+                        .show(ui, |plot| {
+                            plot.line(
+                                Line::new(Values::from_values_iter(
+                                    corners.iter().chain([&corners[0]]).map(|corner| point(corner)),
+                                ))
+                                .color(Color32::GRAY),
+                            );
+                            corners.iter().enumerate().for_each(|(i, corner)| {
+                                let anchor = if i == 2 {
+                                    Align2::CENTER_BOTTOM
+                                } else {
+                                    Align2::CENTER_TOP
+                                };
+                                plot.text(Text::new(point(corner), format!("A{i}")).anchor(anchor));
+                            });
+                            replicator.portrait.iter().for_each(|trajectory| {
+                                plot.line(
+                                    Line::new(Values::from_values_iter(
+                                        trajectory.iter().map(|shares| point(shares)),
+                                    ))
+                                    .color(Color32::DARK_GRAY)
+                                    .name("Phase portrait"),
+                                );
+                            });
+                            plot.line(
+                                Line::new(Values::from_values_iter(
+                                    replicator.trajectory.iter().map(|shares| point(shares)),
+                                ))
+                                .width(2.0)
+                                .color(Color32::YELLOW)
+                                .name("Trajectory"),
+                            );
+                            plot.points(
+                                Points::new(Values::from_values(vec![point(&initial)]))
+                                    .radius(4.0)
+                                    .color(Color32::YELLOW)
+                                    .name("Initial mix"),
+                            );
+                            plot.points(
+                                Points::new(Values::from_values_iter(
+                                    replicator.stable.iter().map(|shares| point(shares)),
+                                ))
+                                .radius(5.0)
+                                .color(Color32::GREEN)
+                                .highlight()
+                                .name("ESS"),
+                            );
+                        });
+                }
+            });
+    }
+}
@@ -0,0 +1,35 @@
+use eframe::egui::{CtxRef, ScrollArea, TextEdit, Window};
+
+use super::super::{file, ReportFormat, SixthLab};
+
+impl SixthLab {
+    pub(super) fn report_window(&mut self, ctx: &CtxRef) {
+        Window::new("Solution Report")
+            .open(&mut self.report_viewer)
+            .show(ctx, |ui| {
+                let mut report = self.game.report(self.report_format, self.fractions);
+                ui.horizontal(|hor| {
+                    ReportFormat::ALL.iter().for_each(|&format| {
+                        hor.selectable_value(&mut self.report_format, format, format.as_str());
+                    });
+                    hor.separator();
+                    if hor.button("Copy").clicked() {
+                        hor.output().copied_text = report.to_string();
+                    }
+                    if hor.button("Save").clicked() {
+                        let name = format!("report.{}", self.report_format.extension());
+                        if let Err(error) = file::save(&name, report) {
+                            self.file_error = Some(error);
+                        }
+                    }
+                });
+                ScrollArea::vertical().max_height(480.0).show(ui, |scroll| {
+                    scroll.add(
+                        TextEdit::multiline(&mut report)
+                            .code_editor()
+                            .desired_width(f32::INFINITY),
+                    );
+                });
+            });
+    }
+}
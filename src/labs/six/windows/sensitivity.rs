@@ -0,0 +1,87 @@
+use eframe::egui::{
+    plot::{Legend, Line, LineStyle, Plot, VLine, Value, Values},
+    CtxRef, DragValue, Window,
+};
+
+use super::super::{format_value, SixthLab, CRITICAL_COLOR, SELECTED_COLOR, SENSITIVITY_LIMIT};
+
+impl SixthLab {
+    pub(super) fn sensitivity_window(&mut self, ctx: &CtxRef) {
+        Window::new("Sensitivity Analysis")
+            .open(&mut self.sensitivity_viewer)
+            .show(ctx, |ui| {
+                if self.game.rows > SENSITIVITY_LIMIT || self.game.columns > SENSITIVITY_LIMIT {
+                    ui.label(format!(
+                        "Sensitivity is analysed for games up to {SENSITIVITY_LIMIT}x{SENSITIVITY_LIMIT}"
+                    ));
+                    return;
+                }
+
+                let cell = &mut self.sensitivity_cell;
+                cell.0 = cell.0.min(self.game.rows - 1);
+                cell.1 = cell.1.min(self.game.columns - 1);
+                ui.horizontal(|hor| {
+                    hor.add(
+                        DragValue::new(&mut cell.0)
+                            .speed(0.05)
+                            .prefix("Row: A")
+                            .clamp_range(0..=self.game.rows - 1),
+                    );
+                    hor.add(
+                        DragValue::new(&mut cell.1)
+                            .speed(0.05)
+                            .prefix("Column: B")
+                            .clamp_range(0..=self.game.columns - 1),
+                    );
+                });
+                let (i, j) = *cell;
+                let payoff = self.game.matrix[i][j];
+                let solution = self.game.exact();
+                let sensitivity = match self.game.sensitivity((i, j)) {
+                    Some(sensitivity) => sensitivity,
+                    None => return,
+                };
+
+                let (low, high) = sensitivity.bounds[i][j];
+                ui.label(format!(
+                    "Supports of the optimal strategies are kept for a{i}{j} in [{}, {}]",
+                    low.map_or("-∞".to_string(), format_value),
+                    high.map_or("+∞".to_string(), format_value)
+                ));
+                if let Some(solution) = &solution {
+                    // The value grows with the payoff as fast as the cell is played
+                    ui.label(format!(
+                        "V = {}, dV/da{i}{j} = p{i} * q{j} = {}",
+                        format_value(solution.value),
+                        format_value(solution.a[i] * solution.b[j])
+                    ));
+                }
+                ui.label("Payoffs close to changing the optimal strategies are red in the matrix");
+
+                Plot::new("sensitivity_curve")
+                    .view_aspect(2.0)
+                    .legend(Legend::default())
+                    .show(ui, |plot| {
+                        plot.line(
+                            Line::new(Values::from_values_iter(
+                                sensitivity.curve.iter().map(|&(x, v)| Value::new(x, v)),
+                            ))
+                            .name(format!("V(a{i}{j})")),
+                        );
+                        plot.vline(
+                            VLine::new(payoff.to_f64())
+                                .color(SELECTED_COLOR)
+                                .name("Current payoff"),
+                        );
+                        [low, high].iter().flatten().for_each(|&bound| {
+                            plot.vline(
+                                VLine::new(bound)
+                                    .color(CRITICAL_COLOR)
+                                    .style(LineStyle::dashed_dense())
+                                    .name("Support changes"),
+                            );
+                        });
+                    });
+            });
+    }
+}
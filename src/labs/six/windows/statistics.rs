@@ -0,0 +1,146 @@
+use std::ops::RangeInclusive;
+
+use eframe::egui::{
+    plot::{Bar, BarChart, Legend, Plot, VLine},
+    Color32, ComboBox, CtxRef, DragValue, Grid, ProgressBar, Window,
+};
+
+use super::super::{format_value, NumberKind, SixthLab, SEED_RANGE, VALUE_LIMIT};
+
+const GAMES_RANGE: RangeInclusive<usize> = 100..=100_000;
+
+// Statistics games are kept small, the time of a frame is checked only between games
+const LENGTH_RANGE: RangeInclusive<usize> = 2..=20;
+
+impl SixthLab {
+    pub(super) fn statistics_window(&mut self, ctx: &CtxRef) {
+        if self.statistics.running() {
+            self.statistics.step();
+            ctx.request_repaint();
+        }
+        Window::new("Statistics")
+            .open(&mut self.statistics_viewer)
+            .show(ctx, |ui| {
+                let statistics = &mut self.statistics;
+                let running = statistics.running();
+
+                ui.add_enabled_ui(!running, |ui| {
+                    Grid::new("statistics_parameters").show(ui, |grid| {
+                        grid.label("Matrix size");
+                        grid.add(
+                            DragValue::new(&mut statistics.length.0)
+                                .speed(0.05)
+                                .prefix("M: ")
+                                .clamp_range(LENGTH_RANGE),
+                        );
+                        grid.add(
+                            DragValue::new(&mut statistics.length.1)
+                                .speed(0.05)
+                                .prefix("N: ")
+                                .clamp_range(LENGTH_RANGE),
+                        );
+                        grid.end_row();
+
+                        let (min, max) = &mut statistics.value_range;
+                        grid.label("Values");
+                        grid.add(
+                            DragValue::new(min)
+                                .speed(0.1)
+                                .prefix("Min: ")
+                                .clamp_range(*VALUE_LIMIT.start()..=*max),
+                        );
+                        grid.add(
+                            DragValue::new(max)
+                                .speed(0.1)
+                                .prefix("Max: ")
+                                .clamp_range(*min..=*VALUE_LIMIT.end()),
+                        );
+                        grid.end_row();
+
+                        grid.label("");
+                        ComboBox::from_id_source("statistics_kind")
+                            .selected_text(statistics.kind.as_str())
+                            .show_ui(grid, |combo| {
+                                NumberKind::ALL.iter().for_each(|option| {
+                                    combo.selectable_value(
+                                        &mut statistics.kind,
+                                        *option,
+                                        option.as_str(),
+                                    );
+                                });
+                            });
+                        grid.end_row();
+
+                        grid.label("Games");
+                        grid.add(
+                            DragValue::new(&mut statistics.count)
+                                .speed(10.0)
+                                .clamp_range(GAMES_RANGE),
+                        );
+                        grid.add(
+                            DragValue::new(&mut statistics.seed)
+                                .speed(0.1)
+                                .prefix("Seed: ")
+                                .clamp_range(SEED_RANGE),
+                        );
+                        grid.end_row();
+                    });
+                });
+
+                ui.horizontal(|hor| {
+                    if running {
+                        if hor.button("Stop").clicked() {
+                            statistics.stop();
+                        }
+                    } else if hor.button("Start").clicked() {
+                        statistics.start();
+                    }
+                    hor.add(
+                        ProgressBar::new(statistics.generated as f32 / statistics.count as f32)
+                            .text(format!("{} / {}", statistics.generated, statistics.count)),
+                    );
+                });
+                if statistics.generated == 0 {
+                    return;
+                }
+
+                ui.separator();
+                let (mean, deviation) = statistics.value_moments();
+                ui.label(format!(
+                    "P(saddle point) = {}",
+                    format_value(statistics.saddle_probability())
+                ));
+                ui.label(format!(
+                    "Average min(max(Bj)) - max(min(Ai)) = {}",
+                    format_value(statistics.average_gap())
+                ));
+                ui.label(format!(
+                    "Game value: mean {}, standard deviation {}",
+                    format_value(mean),
+                    format_value(deviation)
+                ));
+
+                let total = statistics.bins.iter().sum::<usize>().max(1) as f64;
+                let bars = statistics
+                    .bins
+                    .iter()
+                    .enumerate()
+                    .map(|(bin, count)| {
+                        let (lo, hi) = statistics.bin_range(bin);
+                        Bar::new((lo + hi) / 2.0, *count as f64 / total)
+                            .width(hi - lo)
+                            .name(format!("{}..{}", format_value(lo), format_value(hi)))
+                    })
+                    .collect();
+                Plot::new("statistics_histogram")
+                    .view_aspect(2.0)
+                    .legend(Legend::default())
+                    .show(ui, |plot| {
+                        plot.bar_chart(BarChart::new(bars).name("Game value"));
+                        if mean.is_finite() {
+                            plot.vline(VLine::new(mean).color(Color32::GREEN).name("Mean"));
+                        }
+                    });
+            });
+    }
+}
@@ -0,0 +1,280 @@
+use eframe::egui::{
+    plot::{Legend, Line, LineStyle, Plot, Points, Text, Value, Values},
+    Align2, Button, Color32, ComboBox, CtxRef, DragValue, Grid, ScrollArea, TextEdit, Window,
+};
+
+use super::super::{
+    number_edit, Mode, NodeKind, Number, Player, SixthLab, Tree, MAX_MIN_COLOR, MIN_MAX_COLOR,
+    PROBABILITIES_HEIGHT,
+};
+
+impl SixthLab {
+    pub(super) fn tree_window(&mut self, ctx: &CtxRef) {
+        Window::new("Game Tree")
+            .open(&mut self.tree_viewer)
+            .show(ctx, |ui| {
+                let tree = &mut self.tree;
+                let node = &mut self.tree_node;
+                *node = (*node).min(tree.nodes.len() - 1);
+
+                ui.horizontal(|hor| {
+                    let kind = tree.nodes[*node].kind;
+                    if hor
+                        .add_enabled(kind != NodeKind::Terminal, Button::new("Add Action"))
+                        .clicked()
+                    {
+                        *node = tree.add_child(*node);
+                    }
+                    if let Some(parent) = tree.nodes[*node].parent {
+                        if hor.button("Remove").clicked() {
+                            tree.remove(*node);
+                            *node = parent;
+                        }
+                    }
+                    if hor.button("Reset").clicked() {
+                        *tree = Tree::default();
+                        *node = 0;
+                    }
+                    hor.separator();
+                    if hor
+                        .button("Convert")
+                        .on_hover_text("Load the normal form into the matrix")
+                        .clicked()
+                    {
+                        let result = self.game.load_tree(tree);
+                        if result.is_ok() {
+                            self.length = (self.game.rows, self.game.columns);
+                            self.sensitivity_cell = (0, 0);
+                            self.mode = if tree.is_zero_sum() {
+                                Mode::Game
+                            } else {
+                                Mode::Bimatrix
+                            };
+                        }
+                        self.tree_result = Some(result);
+                    }
+                });
+
+                Grid::new("tree_node").num_columns(2).show(ui, |grid| {
+                    grid.label("Node");
+                    grid.label(node.to_string());
+                    grid.end_row();
+
+                    grid.label("Kind");
+                    let mut kind = tree.nodes[*node].kind;
+                    ComboBox::from_id_source("tree_node_kind")
+                        .selected_text(kind.as_str())
+                        .show_ui(grid, |combo| {
+                            NodeKind::ALL.iter().for_each(|option| {
+                                combo.selectable_value(&mut kind, *option, option.as_str());
+                            });
+                        });
+                    if kind != tree.nodes[*node].kind {
+                        tree.set_kind(*node, kind);
+                    }
+                    grid.end_row();
+
+                    let current = &mut tree.nodes[*node];
+                    match current.kind {
+                        NodeKind::Decision(_) => {
+                            grid.label("Information set");
+                            grid.add(
+                                DragValue::new(&mut current.info_set)
+                                    .speed(0.1)
+                                    .clamp_range(0..=99),
+                            );
+                            grid.end_row();
+                        }
+                        NodeKind::Terminal => {
+                            ["Payoff of A", "Payoff of B"]
+                                .iter()
+                                .zip(current.payoff.iter_mut())
+                                .for_each(|(label, payoff)| {
+                                    grid.label(*label);
+                                    let mut value = Number::Rational(*payoff);
+                                    if number_edit(grid, &mut value).changed() {
+                                        *payoff = value.to_rational().unwrap();
+                                    }
+                                    grid.end_row();
+                                });
+                        }
+                        NodeKind::Chance => {}
+                    }
+                    if let Some(parent) = current.parent {
+                        grid.label("Action");
+                        grid.add(TextEdit::singleline(&mut current.action).desired_width(96.0));
+                        grid.end_row();
+                        if tree.nodes[parent].kind == NodeKind::Chance {
+                            grid.label("Probability");
+                            let mut value = Number::Rational(tree.nodes[*node].probability);
+                            if number_edit(grid, &mut value).changed() {
+                                tree.nodes[*node].probability = value.to_rational().unwrap();
+                            }
+                            grid.end_row();
+                        }
+                    }
+                });
+
+                let positions = tree.layout();
+                let response = Plot::new("game_tree")
+                    .view_aspect(1.5)
+                    .show_x(false)
+                    .show_y(false)
+                    .show_axes([false, false])
+                    .legend(Legend::default())
+                    .show(ui, |plot| {
+                        tree.nodes.iter().enumerate().for_each(|(i, current)| {
+                            let parent = match current.parent {
+                                Some(parent) => parent,
+                                None => return,
+                            };
+                            let (from, to) = (positions[parent], positions[i]);
+                            plot.line(
+                                Line::new(Values::from_values(vec![
+                                    Value::new(from.0, from.1),
+                                    Value::new(to.0, to.1),
+                                ]))
+                                .color(Color32::GRAY),
+                            );
+                            let label = if tree.nodes[parent].kind == NodeKind::Chance {
+                                format!("{} ({})", current.action, current.probability)
+                            } else {
+                                current.action.clone()
+                            };
+                            plot.text(Text::new(
+                                Value::new((from.0 + to.0) / 2.0, (from.1 + to.1) / 2.0),
+                                label,
+                            ));
+                        });
+
+                        // Nodes of one information set are joined by a dashed line
+                        [Player::A, Player::B].iter().for_each(|&player| {
+                            tree.info_sets(player).iter().for_each(|set| {
+                                let mut xs = tree
+                                    .nodes
+                                    .iter()
+                                    .enumerate()
+                                    .filter(|(_, other)| {
+                                        other.kind == NodeKind::Decision(player)
+                                            && other.info_set == set.id
+                                    })
+                                    .map(|(i, _)| positions[i])
+                                    .collect::<Vec<(f64, f64)>>();
+                                if xs.len() > 1 {
+                                    xs.sort_by(|a, b| a.0.partial_cmp(&b.0).unwrap());
+                                    plot.line(
+                                        Line::new(Values::from_values_iter(
+                                            xs.iter().map(|(x, y)| Value::new(*x, *y)),
+                                        ))
+                                        .style(LineStyle::dashed_dense())
+                                        .color(Color32::YELLOW),
+                                    );
+                                }
+                            });
+                        });
+
+                        NodeKind::ALL.iter().for_each(|&kind| {
+                            let color = match kind {
+                                NodeKind::Decision(Player::A) => MAX_MIN_COLOR,
+                                NodeKind::Decision(Player::B) => MIN_MAX_COLOR,
+                                NodeKind::Chance => Color32::GRAY,
+                                NodeKind::Terminal => Color32::WHITE,
+                            };
+                            plot.points(
+                                Points::new(Values::from_values_iter(
+                                    tree.nodes
+                                        .iter()
+                                        .zip(positions.iter())
+                                        .filter(|(current, _)| current.kind == kind)
+                                        .map(|(_, (x, y))| Value::new(*x, *y)),
+                                ))
+                                .radius(5.0)
+                                .color(color)
+                                .name(kind.as_str()),
+                            );
+                        });
+                        let selected = positions[*node];
+                        plot.points(
+                            Points::new(Values::from_values(vec![Value::new(
+                                selected.0, selected.1,
+                            )]))
+                            .radius(8.0)
+                            .color(Color32::GOLD)
+                            .name("Selected"),
+                        );
+
+                        tree.nodes
+                            .iter()
+                            .zip(positions.iter())
+                            .for_each(|(current, (x, y))| {
+                                let (text, anchor, dy) = match current.kind {
+                                    NodeKind::Decision(player) => (
+                                        format!("{}:{}", player.as_str(), current.info_set),
+                                        Align2::CENTER_BOTTOM,
+                                        0.1,
+                                    ),
+                                    NodeKind::Chance => {
+                                        ("N".to_string(), Align2::CENTER_BOTTOM, 0.1)
+                                    }
+                                    NodeKind::Terminal => (
+                                        format!("({}, {})", current.payoff[0], current.payoff[1]),
+                                        Align2::CENTER_TOP,
+                                        -0.1,
+                                    ),
+                                };
+                                plot.text(Text::new(Value::new(*x, y + dy), text).anchor(anchor));
+                            });
+
+                        plot.pointer_coordinate()
+                    });
+
+                // Click selects the nearest node
+                if let (true, Some(pointer)) = (response.response.clicked(), response.inner) {
+                    let distance = |(x, y): (f64, f64)| (x - pointer.x).hypot(y - pointer.y);
+                    if let Some(i) = (0..positions.len())
+                        .filter(|&i| distance(positions[i]) < 0.4)
+                        .min_by(|&i, &j| {
+                            distance(positions[i])
+                                .partial_cmp(&distance(positions[j]))
+                                .unwrap()
+                        })
+                    {
+                        *node = i;
+                    }
+                }
+
+                match &self.tree_result {
+                    Some(Ok(normal_form)) => {
+                        ui.label(format!(
+                            "Normal form: {}x{}",
+                            normal_form.strategies_a.len(),
+                            normal_form.strategies_b.len()
+                        ));
+                        ScrollArea::vertical()
+                            .max_height(PROBABILITIES_HEIGHT)
+                            .show(ui, |scroll| {
+                                [
+                                    (Player::A, &normal_form.strategies_a),
+                                    (Player::B, &normal_form.strategies_b),
+                                ]
+                                .iter()
+                                .for_each(
+                                    |(player, strategies)| {
+                                        strategies.iter().enumerate().for_each(|(k, strategy)| {
+                                            scroll.label(format!(
+                                                "{}{k} = {strategy}",
+                                                player.as_str()
+                                            ));
+                                        });
+                                    },
+                                );
+                            });
+                    }
+                    Some(Err(error)) => {
+                        ui.colored_label(Color32::RED, error);
+                    }
+                    None => {}
+                }
+            });
+    }
+}
@@ -0,0 +1,199 @@
+use std::hash::Hash;
+
+use eframe::egui::{Align, Id, Layout, Pos2, Rect, ScrollArea, Ui, Vec2};
+
+// Tolerance used when matching the viewport to the cell borders
+const EPS: f32 = 1e-3;
+
+// Width of the label column, fits short labels like "max(Bj)"
+const LABEL_WIDTH: f32 = 64.0;
+
+#[derive(Clone, Copy, Debug, PartialEq)]
+pub enum Row {
+    Header,
+    Body(usize),
+    Footer,
+}
+
+#[derive(Clone, Copy, Debug, PartialEq)]
+pub enum Column {
+    Label,
+    Body(usize),
+}
+
+// Table of equally sized cells which adds only the visible ones, so it stays fast with
+// hundreds of rows and columns. The header row, the footer row and the label column
+// stay in place while scrolling
+pub struct Table {
+    id: Id,
+    rows: usize,
+    columns: usize,
+    footer: bool,
+    cell_width: f32,
+    max_size: Vec2,
+}
+
+impl Table {
+    pub fn new(id_source: impl Hash, rows: usize, columns: usize) -> Self {
+        Self {
+            id: Id::new(id_source),
+            rows,
+            columns,
+            footer: false,
+            cell_width: 64.0,
+            max_size: Vec2::INFINITY,
+        }
+    }
+
+    pub fn footer(mut self, footer: bool) -> Self {
+        self.footer = footer;
+        self
+    }
+
+    pub fn cell_width(mut self, width: f32) -> Self {
+        self.cell_width = width;
+        self
+    }
+
+    pub fn max_width(mut self, width: f32) -> Self {
+        self.max_size.x = width;
+        self
+    }
+
+    pub fn max_height(mut self, height: f32) -> Self {
+        self.max_size.y = height;
+        self
+    }
+
+    pub fn show(self, ui: &mut Ui, mut add_cell: impl FnMut(&mut Ui, Row, Column)) {
+        let size = Vec2::new(
+            self.cell_width,
+            ui.spacing().interact_size.y + ui.spacing().item_spacing.y,
+        );
+        let footer = usize::from(self.footer);
+        let total = Vec2::new(
+            LABEL_WIDTH + size.x * self.columns as f32,
+            size.y * (self.rows + 1 + footer) as f32,
+        );
+
+        ScrollArea::both()
+            .id_source(self.id)
+            .max_width(self.max_size.x)
+            .max_height(self.max_size.y)
+            .show_viewport(ui, |ui, viewport| {
+                ui.set_min_size(total);
+                let origin = ui.max_rect().min;
+                let max = Pos2::new(viewport.max.x.min(total.x), viewport.max.y.min(total.y));
+
+                // Partially visible body cells are skipped, so they never get under the sticky ones
+                let rows = (viewport.min.y / size.y - EPS).ceil() as usize
+                    ..((max.y / size.y + EPS).floor() as usize)
+                        .saturating_sub(1 + footer)
+                        .min(self.rows);
+                let columns = (viewport.min.x / size.x - EPS).ceil() as usize
+                    ..(((max.x - LABEL_WIDTH) / size.x + EPS).floor().max(0.0) as usize)
+                        .min(self.columns);
+
+                let header_y = viewport.min.y;
+                let footer_y = max.y - size.y;
+                let label_x = viewport.min.x;
+                let cell_rect = |x: f32, y: f32, width: f32| {
+                    Rect::from_min_size(origin + Vec2::new(x, y), Vec2::new(width, size.y))
+                };
+                let row_y = |i: usize| size.y * (i + 1) as f32;
+                let column_x = |j: usize| LABEL_WIDTH + size.x * j as f32;
+
+                // Stripes
+                rows.clone().filter(|i| i % 2 == 1).for_each(|i| {
+                    ui.painter().rect_filled(
+                        cell_rect(label_x, row_y(i), max.x - label_x),
+                        0.0,
+                        ui.visuals().faint_bg_color,
+                    );
+                });
+
+                // Body
+                let mut lane = Lane::new(ui);
+                rows.clone().for_each(|i| {
+                    columns.clone().for_each(|j| {
+                        let mut cell = lane.cell(
+                            i * self.columns + j,
+                            cell_rect(column_x(j), row_y(i), size.x),
+                        );
+                        add_cell(&mut cell, Row::Body(i), Column::Body(j));
+                    })
+                });
+
+                // Header and footer rows
+                let mut sticky_rows = vec![(Row::Header, header_y)];
+                if self.footer {
+                    sticky_rows.push((Row::Footer, footer_y));
+                }
+                sticky_rows.into_iter().for_each(|(row, y)| {
+                    let mut lane = Lane::new(ui);
+                    let mut cell = lane.cell(0, cell_rect(label_x, y, LABEL_WIDTH));
+                    add_cell(&mut cell, row, Column::Label);
+                    columns.clone().for_each(|j| {
+                        let mut cell = lane.cell(j + 1, cell_rect(column_x(j), y, size.x));
+                        add_cell(&mut cell, row, Column::Body(j));
+                    });
+                });
+
+                // Label column
+                let mut lane = Lane::new(ui);
+                rows.clone().for_each(|i| {
+                    let mut cell = lane.cell(i, cell_rect(label_x, row_y(i), LABEL_WIDTH));
+                    add_cell(&mut cell, Row::Body(i), Column::Label);
+                });
+
+                // Borders of the sticky cells
+                let stroke = ui.visuals().widgets.noninteractive.bg_stroke;
+                let (left, right) = (origin.x + label_x, origin.x + max.x);
+                let (top, bottom) = (origin.y + header_y, origin.y + max.y);
+                let mut lines = vec![
+                    [
+                        Pos2::new(left, top + size.y),
+                        Pos2::new(right, top + size.y),
+                    ],
+                    [
+                        Pos2::new(left + LABEL_WIDTH, top),
+                        Pos2::new(left + LABEL_WIDTH, bottom),
+                    ],
+                ];
+                if self.footer {
+                    lines.push([
+                        Pos2::new(left, origin.y + footer_y),
+                        Pos2::new(right, origin.y + footer_y),
+                    ]);
+                }
+                lines
+                    .into_iter()
+                    .for_each(|line| ui.painter().line_segment(line, stroke));
+            });
+    }
+}
+
+// Child uis of one part of the table, auto ids inside each cell depend only on its index,
+// so widget states stay with their cells while scrolling
+struct Lane {
+    ui: Ui,
+    next: usize,
+}
+
+impl Lane {
+    fn new(ui: &mut Ui) -> Self {
+        Self {
+            ui: ui.child_ui(ui.max_rect(), *ui.layout()),
+            next: 0,
+        }
+    }
+
+    fn cell(&mut self, index: usize, rect: Rect) -> Ui {
+        self.ui.skip_ahead_auto_ids(index - self.next);
+        self.next = index + 1;
+        self.ui.child_ui(
+            rect,
+            Layout::left_to_right().with_cross_align(Align::Center),
+        )
+    }
+}
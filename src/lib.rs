@@ -6,7 +6,7 @@ use eframe::{
 
 mod labs;
 
-pub use labs::LabsApp;
+pub use labs::{solve, LabsApp};
 
 #[cfg(target_arch = "wasm32")]
 #[wasm_bindgen]
@@ -0,0 +1,75 @@
+use std::{
+    io::Write,
+    process::{Command, Output, Stdio},
+};
+
+use serde_json::{json, Value};
+
+const MATRIX: &str = "2,6,4\n0,0,5\n4,-4,4\n";
+
+// Runs the solver with the matrix on stdin
+fn solve(args: &[&str]) -> Output {
+    let mut child = Command::new(env!("CARGO_BIN_EXE_ma-labs-solve"))
+        .args(args)
+        .stdin(Stdio::piped())
+        .stdout(Stdio::piped())
+        .stderr(Stdio::piped())
+        .spawn()
+        .unwrap();
+    child
+        .stdin
+        .take()
+        .unwrap()
+        .write_all(MATRIX.as_bytes())
+        .unwrap();
+    child.wait_with_output().unwrap()
+}
+
+fn stdout(args: &[&str]) -> String {
+    let output = solve(args);
+    assert!(output.status.success());
+    String::from_utf8(output.stdout).unwrap()
+}
+
+#[test]
+fn prints_fractions_or_decimals() {
+    let fractions = stdout(&[]);
+    assert!(fractions.contains("Saddle points: none"));
+    assert!(fractions.contains("V = 8/3"));
+    assert!(fractions.contains("P = (2/3, 0, 1/3)"));
+
+    let decimals = stdout(&["--decimals", "-"]);
+    assert!(decimals.contains("V = 2.6667"));
+    assert!(decimals.contains("Q = (0.8333, 0.1667, 0)"));
+}
+
+#[test]
+fn prints_json_document() {
+    let document = serde_json::from_str::<Value>(&stdout(&["--json"])).unwrap();
+    assert_eq!(document["kind"], "Integer");
+    assert_eq!(
+        (document["rows"].as_u64(), document["columns"].as_u64()),
+        (Some(3), Some(3))
+    );
+    assert_eq!(document["matrix"][2], json!([4, -4, 4]));
+
+    let results = &document["results"];
+    assert_eq!(results["a_min"], json!([2, 0, -4]));
+    assert_eq!(results["b_max"], json!([4, 6, 5]));
+    assert_eq!(
+        (&results["max_min"], &results["min_max"]),
+        (&Value::from(2), &Value::from(4))
+    );
+    assert_eq!(results["saddle_points"], json!([]));
+    assert_eq!(results["mixed"]["exact"]["value"], "8/3");
+    assert!((results["mixed"]["value"].as_f64().unwrap() - 8.0 / 3.0).abs() < 1e-9);
+}
+
+#[test]
+fn rejects_unknown_options() {
+    let output = solve(&["--fractions"]);
+    assert!(!output.status.success());
+    assert!(String::from_utf8(output.stderr)
+        .unwrap()
+        .starts_with("Unknown option \"--fractions\""));
+}